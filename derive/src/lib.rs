@@ -0,0 +1,153 @@
+//! # Solana Helpers Derive
+//!
+//! Derive macros for `solana-helpers`, re-exported from the modules that define the
+//! traits they implement.
+
+#![warn(clippy::all, clippy::pedantic, clippy::nursery)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, Type};
+
+/// Derives `solana_helpers::helpers::space::AccountSpace` from the Borsh layout of a
+/// struct or enum.
+///
+/// Structs sum the sizes of their fields. Enums take one tag byte plus the largest
+/// variant. Type parameters must implement `AccountSpace` themselves.
+///
+/// `#[account_space(header = Type)]` reserves `Type::MAX_SPACE` bytes in front of the
+/// fields, for a discriminator or account header.
+#[proc_macro_derive(AccountSpace, attributes(account_space))]
+pub fn derive_account_space(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let space_trait = quote!(::solana_helpers::helpers::space::AccountSpace);
+    let header = header_type(&input)?;
+    let prefix_space = header.map(|header| {
+        quote! {
+            const PREFIX_SPACE: usize = <#header as #space_trait>::MAX_SPACE;
+        }
+    });
+    let (max_space, space) = match &input.data {
+        Data::Struct(data) => {
+            let (max_space, space) = fields_space(&data.fields, &space_trait);
+            let bindings = bindings(&data.fields);
+            (
+                max_space,
+                quote! {
+                    let Self #bindings = self;
+                    #space
+                },
+            )
+        }
+        Data::Enum(data) => {
+            let variant_spaces = data
+                .variants
+                .iter()
+                .map(|variant| fields_space(&variant.fields, &space_trait).0);
+            let arms = data.variants.iter().map(|variant| {
+                let name = &variant.ident;
+                let bindings = bindings(&variant.fields);
+                let space = fields_space(&variant.fields, &space_trait).1;
+                quote!(Self::#name #bindings => #space,)
+            });
+            (
+                quote! {
+                    1 + ::solana_helpers::helpers::space::max_of(&[#(#variant_spaces),*])
+                },
+                quote! {
+                    1 + match self {
+                        #(#arms)*
+                    }
+                },
+            )
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "AccountSpace can't be derived for unions",
+            ))
+        }
+    };
+
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = input.generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: #space_trait));
+    }
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #space_trait for #name #ty_generics #where_clause {
+            #prefix_space
+            const MAX_SPACE: usize = #max_space;
+
+            #[allow(unused_variables)]
+            fn space(&self) -> usize {
+                #space
+            }
+        }
+    })
+}
+
+/// Reads the type named by `#[account_space(header = Type)]`, if any.
+fn header_type(input: &DeriveInput) -> syn::Result<Option<Type>> {
+    let mut header = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("account_space"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("header") {
+                header = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `header = Type`"))
+            }
+        })?;
+    }
+    Ok(header)
+}
+
+/// Returns the largest and the actual size of `fields`, the latter in terms of the
+/// names `bindings` gives them.
+fn fields_space(fields: &Fields, space_trait: &TokenStream2) -> (TokenStream2, TokenStream2) {
+    let types = fields.iter().map(|field| &field.ty);
+    let names = field_names(fields);
+    (
+        quote!(0 #(+ <#types as #space_trait>::MAX_SPACE)*),
+        quote!(0 #(+ #space_trait::space(#names))*),
+    )
+}
+
+/// Returns a pattern binding every field of `fields` to the names of `field_names`.
+fn bindings(fields: &Fields) -> TokenStream2 {
+    let bound = field_names(fields);
+    match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote!({ #(#idents: #bound),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#bound),* )),
+        Fields::Unit => quote!(),
+    }
+}
+
+fn field_names(fields: &Fields) -> Vec<proc_macro2::Ident> {
+    (0..fields.len())
+        .map(|index| format_ident!("field_{}", index))
+        .collect()
+}
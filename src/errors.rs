@@ -19,14 +19,121 @@ pub enum Errors {
     WrongPdaAddress,
     #[error("Can't convert ProgramResult")]
     CantConvertProgramResult,
+    /// Indicates fewer accounts were supplied than the instruction requires.
+    #[error("Not Enough Account Keys")]
+    NotEnoughAccountKeys,
+    /// Indicates an account is not owned by the program expected to own it.
+    #[error("Invalid Account Owner")]
+    InvalidAccountOwner,
+    /// Indicates account data could not be unpacked or is not initialized.
+    #[error("Invalid Account Data")]
+    InvalidAccountData,
+    /// Indicates a token account belongs to a different mint than expected.
+    #[error("Mint Mismatch")]
+    MintMismatch,
+    /// Indicates a token account holds fewer tokens than required.
+    #[error("Insufficient Funds")]
+    InsufficientFunds,
+    /// Indicates a token account is frozen.
+    #[error("Account Frozen")]
+    AccountFrozen,
+    /// Indicates a token account has a delegate when none is allowed.
+    #[error("Unexpected Delegate")]
+    UnexpectedDelegate,
+    /// Indicates a mint's authority doesn't match the expected authority.
+    #[error("Mint Authority Mismatch")]
+    MintAuthorityMismatch,
+    /// Indicates a mint's decimals don't match the expected decimals.
+    #[error("Decimals Mismatch")]
+    DecimalsMismatch,
+    /// Indicates a CPI target is not the program the helper expects to invoke.
+    #[error("Invalid Program Id")]
+    InvalidProgramId,
+    /// Indicates an account would be left below the rent-exempt minimum.
+    #[error("Insufficient Funds For Rent")]
+    InsufficientFundsForRent,
+    /// Indicates an account to be created already has data or a non-system owner.
+    #[error("Account Already In Use")]
+    AccountAlreadyInUse,
+    /// Indicates an account's owner is not supported by the requested operation.
+    #[error("Unsupported Account Owner")]
+    UnsupportedAccountOwner,
+    /// Indicates a refund destination is not the recorded rent payer.
+    #[error("Rent Payer Mismatch")]
+    RentPayerMismatch,
+    /// Indicates an account header that doesn't record a rent payer.
+    #[error("Rent Payer Not Recorded")]
+    RentPayerNotRecorded,
+    /// Indicates a signer is not a member of the multisig authority.
+    #[error("Invalid Multisig Signer")]
+    InvalidMultisigSigner,
+    /// Indicates fewer multisig members are present than the multisig threshold.
+    #[error("Not Enough Signers")]
+    NotEnoughSigners,
+    /// Indicates seeds and bump don't derive a valid program address.
+    #[error("Invalid Seeds")]
+    InvalidSeeds,
+    /// Indicates seed values don't match the parts of a seed schema.
+    #[error("Seed Schema Mismatch")]
+    SeedSchemaMismatch,
+    /// Indicates a value is longer than the capacity of its fixed-size container.
+    #[error("Fixed Capacity Exceeded")]
+    FixedCapacityExceeded,
+    /// Indicates bytes stored as a string are not valid UTF-8.
+    #[error("Invalid Utf8")]
+    InvalidUtf8,
+    /// Indicates account data starts with the discriminator of another account type.
+    #[error("Discriminator Mismatch")]
+    DiscriminatorMismatch,
+    /// Indicates an account schema version this program can't read.
+    #[error("Unsupported Account Version")]
+    UnsupportedAccountVersion,
+    /// Indicates an index past the end of a collection.
+    #[error("Index Out Of Bounds")]
+    IndexOutOfBounds,
+    /// Indicates an index of a claim bitmap was already claimed.
+    #[error("Already Claimed")]
+    AlreadyClaimed,
+    /// Indicates a merkle proof doesn't lead to the expected root.
+    #[error("Invalid Merkle Proof")]
+    InvalidMerkleProof,
+    /// Indicates a required signer didn't sign.
+    #[error("Missing Required Signature")]
+    MissingRequiredSignature,
+    /// Indicates a claim on a distributor past its expiry.
+    #[error("Distributor Expired")]
+    DistributorExpired,
+    /// Indicates a clawback from a distributor before its expiry.
+    #[error("Distributor Not Expired")]
+    DistributorNotExpired,
+    /// Indicates the number of public inputs doesn't match the verifying key.
+    #[error("Public Input Count Mismatch")]
+    PublicInputCountMismatch,
+    /// Indicates a public input is not below the scalar field modulus.
+    #[error("Public Input Out Of Field")]
+    PublicInputOutOfField,
+    /// Indicates a curve point is malformed or not on the curve.
+    #[error("Invalid Curve Point")]
+    InvalidCurvePoint,
+    /// Indicates a proof failed the pairing check.
+    #[error("Proof Verification Failed")]
+    ProofVerificationFailed,
+    /// Indicates a snarkjs artifact is malformed or not a BN254 Groth16 artifact.
+    #[error("Invalid Snarkjs Artifact")]
+    InvalidSnarkjsArtifact,
+    /// Indicates a value is not a canonical element of the BN254 scalar field.
+    #[error("Field Element Out Of Range")]
+    FieldElementOutOfRange,
+    /// Indicates Poseidon inputs the hash doesn't accept.
+    #[error("Invalid Poseidon Input")]
+    InvalidPoseidonInput,
 }
 
 impl From<ProgramResult> for Errors {
     fn from(value: ProgramResult) -> Self {
-        match value.err() {
-            Some(err) => Self::from(err),
-            None => Self::CantConvertProgramResult,
-        }
+        value
+            .err()
+            .map_or(Self::CantConvertProgramResult, Self::from)
     }
 }
 
@@ -0,0 +1,100 @@
+use crate::errors::Errors;
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+/// Holds the accounts of the current instruction and hands out references that
+/// live exactly as long as the instruction's account slice.
+///
+/// Structs that need to keep accounts around can borrow them from the context
+/// with the `'info` lifetime instead of extending it to `'static`.
+pub struct AccountContext<'a, 'info> {
+    program_id: &'a Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    cursor: usize,
+}
+
+impl<'a, 'info> AccountContext<'a, 'info> {
+    /// Creates a context over the accounts passed to the program entrypoint.
+    #[must_use]
+    pub const fn new(program_id: &'a Pubkey, accounts: &'a [AccountInfo<'info>]) -> Self {
+        Self {
+            program_id,
+            accounts,
+            cursor: 0,
+        }
+    }
+
+    /// Returns the id of the program executing the instruction.
+    #[must_use]
+    pub const fn program_id(&self) -> &'a Pubkey {
+        self.program_id
+    }
+
+    /// Returns every account of the instruction.
+    #[must_use]
+    pub const fn accounts(&self) -> &'a [AccountInfo<'info>] {
+        self.accounts
+    }
+
+    /// Returns the accounts that have not been consumed by `next_account`/`next_accounts`.
+    #[must_use]
+    pub fn remaining(&self) -> &'a [AccountInfo<'info>] {
+        &self.accounts[self.cursor..]
+    }
+
+    /// Returns the account at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NotEnoughAccountKeys` if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Result<&'a AccountInfo<'info>, Errors> {
+        self.accounts.get(index).ok_or(Errors::NotEnoughAccountKeys)
+    }
+
+    /// Returns the first account whose key equals `key`.
+    #[must_use]
+    pub fn find(&self, key: &Pubkey) -> Option<&'a AccountInfo<'info>> {
+        self.accounts.iter().find(|account| account.key == key)
+    }
+
+    /// Consumes and returns the next account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NotEnoughAccountKeys` if all accounts have been consumed.
+    pub fn next_account(&mut self) -> Result<&'a AccountInfo<'info>, Errors> {
+        let account = self.get(self.cursor)?;
+        self.cursor += 1;
+        Ok(account)
+    }
+
+    /// Consumes and returns the next `count` accounts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NotEnoughAccountKeys` if fewer than `count` accounts remain.
+    pub fn next_accounts(&mut self, count: usize) -> Result<&'a [AccountInfo<'info>], Errors> {
+        let end = self
+            .cursor
+            .checked_add(count)
+            .ok_or(Errors::NumericalOverflow)?;
+        let accounts = self
+            .accounts
+            .get(self.cursor..end)
+            .ok_or(Errors::NotEnoughAccountKeys)?;
+        self.cursor = end;
+        Ok(accounts)
+    }
+
+    /// Returns an owned clone of the account at `index`.
+    ///
+    /// The clone shares lamports and data with the original and keeps its `'info`
+    /// lifetime, so it can be passed by value to the CPI helpers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NotEnoughAccountKeys` if `index` is out of bounds.
+    pub fn clone_account(&self, index: usize) -> Result<AccountInfo<'info>, Errors> {
+        self.get(index).cloned()
+    }
+}
@@ -0,0 +1,150 @@
+use crate::errors::Errors;
+use crate::helpers::collections::Bitmap;
+use crate::helpers::generic::Generic;
+use crate::helpers::pda_signer::PdaSigner;
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use solana_program::pubkey::Pubkey;
+
+/// An "already claimed" set over indexes `0..`, split across chunk PDAs of `BITS` bits.
+///
+/// Chunk `n` holds indexes `n * BITS..(n + 1) * BITS` and lives at the PDA of
+/// `["claims", namespace, n as u64 LE]` under the program, so one program can keep a
+/// separate set per namespace, e.g. per distributor. Chunks are created on demand with
+/// `create_chunk`, and clients find the chunk holding an index with `chunk_address`.
+///
+/// A chunk account stores the bump of its address followed by the bitmap, so claims
+/// check the address with `create_program_address` instead of searching for the bump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimBitmap<const BITS: usize> {
+    program_id: Pubkey,
+    namespace: Pubkey,
+}
+
+impl<const BITS: usize> ClaimBitmap<BITS> {
+    /// Bytes a chunk account takes: the bump, then the bitmap.
+    pub const CHUNK_SPACE: usize = Self::BITMAP_OFFSET + Bitmap::<BITS, &'static [u8]>::SPACE;
+
+    /// Offset of the bitmap in a chunk account, after the bump.
+    const BITMAP_OFFSET: usize = 1;
+
+    /// Creates the set for `namespace` under `program_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, at compile time when evaluated in a `const`, if `BITS` is zero or a chunk
+    /// is too large to be created by a CPI.
+    #[must_use]
+    pub const fn new(program_id: Pubkey, namespace: Pubkey) -> Self {
+        assert!(BITS > 0, "a chunk must hold at least one bit");
+        assert!(
+            Self::CHUNK_SPACE <= MAX_PERMITTED_DATA_INCREASE,
+            "a chunk must fit in a single account creation"
+        );
+        Self {
+            program_id,
+            namespace,
+        }
+    }
+
+    /// Returns the number of the chunk holding `index`.
+    #[must_use]
+    pub const fn chunk(index: u64) -> u64 {
+        index / BITS as u64
+    }
+
+    /// Returns the address and bump of chunk number `chunk`.
+    #[must_use]
+    pub fn chunk_pda(&self, chunk: u64) -> (Pubkey, u8) {
+        let chunk = chunk.to_le_bytes();
+        Pubkey::find_program_address(&self.chunk_seeds(&chunk), &self.program_id)
+    }
+
+    /// Returns the address of the chunk holding `index`, for clients building the
+    /// accounts of a claim.
+    #[must_use]
+    pub fn chunk_address(&self, index: u64) -> Pubkey {
+        self.chunk_pda(Self::chunk(index)).0
+    }
+
+    /// Creates chunk number `chunk`, paid by `payer`, and records its bump.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `Generic::create_pda_account_with_signer` fails, e.g. because `chunk_account` is
+    ///   not the chunk PDA or already exists
+    /// - Unable to borrow the chunk data to record the bump
+    pub fn create_chunk<'a, 'info>(
+        &self,
+        chunk_account: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        payer: &'a AccountInfo<'info>,
+        chunk: u64,
+    ) -> Result<(), Errors> {
+        let chunk = chunk.to_le_bytes();
+        let signer = PdaSigner::derive(&self.program_id, &self.chunk_seeds(&chunk));
+        Generic::create_pda_account_with_signer(
+            chunk_account,
+            system_program,
+            payer,
+            Self::CHUNK_SPACE,
+            &signer,
+        )?;
+        chunk_account.try_borrow_mut_data()?[0] = signer.bump();
+        Ok(())
+    }
+
+    /// Returns whether `index` is claimed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `chunk_account` is not the chunk holding `index` (see `claim`).
+    pub fn is_claimed(&self, chunk_account: &AccountInfo, index: u64) -> Result<bool, Errors> {
+        self.assert_chunk(chunk_account, index)?;
+        Bitmap::<BITS, _>::load(chunk_account, Self::BITMAP_OFFSET)?.get(Self::bit(index))
+    }
+
+    /// Marks `index` as claimed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `index` is already claimed (`Errors::AlreadyClaimed`)
+    /// - `chunk_account` is not owned by the program (`Errors::InvalidAccountOwner`)
+    /// - `chunk_account` is not the PDA of the chunk holding `index` with its recorded
+    ///   bump (`Errors::WrongPdaAddress`)
+    /// - The chunk data can't be borrowed or is too short
+    pub fn claim(&self, chunk_account: &AccountInfo, index: u64) -> Result<(), Errors> {
+        self.assert_chunk(chunk_account, index)?;
+        if Bitmap::<BITS, _>::load_mut(chunk_account, Self::BITMAP_OFFSET)?
+            .test_and_set(Self::bit(index))?
+        {
+            return Err(Errors::AlreadyClaimed);
+        }
+        Ok(())
+    }
+
+    fn chunk_seeds<'s>(&'s self, chunk: &'s [u8; 8]) -> [&'s [u8]; 3] {
+        [b"claims", self.namespace.as_ref(), chunk]
+    }
+
+    fn assert_chunk(&self, chunk_account: &AccountInfo, index: u64) -> Result<(), Errors> {
+        if *chunk_account.owner != self.program_id {
+            return Err(Errors::InvalidAccountOwner);
+        }
+        let bump = *chunk_account
+            .try_borrow_data()?
+            .first()
+            .ok_or(Errors::InvalidAccountData)?;
+        let chunk = Self::chunk(index).to_le_bytes();
+        PdaSigner::with_bump(&self.program_id, &self.chunk_seeds(&chunk), bump)
+            .and_then(|signer| signer.verify(chunk_account))
+            .map_err(|_| Errors::WrongPdaAddress)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    const fn bit(index: u64) -> usize {
+        (index % BITS as u64) as usize
+    }
+}
@@ -1,11 +1,56 @@
 use crate::errors::Errors;
+use crate::helpers::loaders::{MintLoader, TokenAccountLoader};
+use crate::helpers::pda_signer::PdaSigner;
+use crate::helpers::rent_payer::RentPayer;
 use crate::helpers::transfers::Transfers;
+use crate::helpers::validators::Validators;
 use solana_program::account_info::AccountInfo;
 use solana_program::program_memory::sol_memset;
+use solana_program::pubkey::Pubkey;
+use spl_token_2022::extension::transfer_fee::instruction::harvest_withheld_tokens_to_mint;
+use spl_token_2022::extension::transfer_fee::TransferFeeAmount;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
 
 /// Provides utilities for closing accounts and transferring lamports.
 pub struct Closers;
 
+/// Why `Closers::close_many` left an account open.
+#[derive(Debug, Clone)]
+pub enum CloseSkipReason {
+    /// The account was not passed as writable.
+    NotWritable,
+    /// The account holds no lamports, e.g. it was already closed or listed twice.
+    AlreadyClosed,
+    /// The account is the refund recipient itself.
+    IsRecipient,
+    /// The account is owned by neither the caller, the System program nor a token program.
+    UnsupportedOwner,
+    /// The account is owned by the caller but `is_closable` rejected it.
+    NotClosable,
+    /// The token account is owned by neither `authority` nor has it as close authority.
+    WrongAuthority,
+    /// The token account still holds tokens or withheld transfer fees.
+    NonEmptyTokenAccount,
+    /// The system account has data, so it can't be drained with a system transfer.
+    SystemAccountHasData,
+    /// No signer seeds were provided for the system-owned account.
+    MissingSignerSeeds,
+    /// Reading the account, `is_closable` or the closer returned an error. On-chain a
+    /// failing program invocation aborts the whole instruction instead of landing here.
+    Failed(Errors),
+}
+
+/// Outcome of `Closers::close_many`.
+#[derive(Debug, Clone, Default)]
+pub struct CloseManyReport {
+    /// Keys of the accounts that were closed.
+    pub closed: Vec<Pubkey>,
+    /// Keys of the accounts left open, with the reason.
+    pub skipped: Vec<(Pubkey, CloseSkipReason)>,
+    /// Total lamports refunded to the recipient.
+    pub refunded: u64,
+}
+
 impl Closers {
     /// Closes a PDA account by transferring all lamports to a destination account.
     ///
@@ -22,47 +67,356 @@ impl Closers {
         Ok(())
     }
 
-    /// Closes a regular account by clearing its data, transferring lamports, and reassigning ownership.
+    /// Closes a PDA whose `AccountHeader` records a rent payer, see
+    /// `Generic::create_pda_account_with_rent_payer`, refunding its lamports only to that
+    /// payer.
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - `from` isn't owned by `program_id` (`Errors::InvalidAccountOwner`)
+    /// - `to` is not the recorded rent payer (`Errors::RentPayerMismatch`)
+    /// - No rent payer is recorded (`Errors::RentPayerNotRecorded`) or the header can't
+    ///   be read
+    /// - The transfer of lamports fails
     /// - Unable to borrow account data for memory clearing
+    pub fn close_pda_to_rent_payer(
+        from: &mut AccountInfo,
+        to: &mut AccountInfo,
+        program_id: &Pubkey,
+    ) -> Result<(), Errors> {
+        RentPayer::assert_payer(from, program_id, to.key)?;
+        Self::close_pda(from, to)
+    }
+
+    /// Closes an account, picking the strategy that matches its owner.
+    ///
+    /// - Accounts owned by `program_id` have their data cleared and truncated, are
+    ///   reassigned to the System program and have their lamports moved directly.
+    /// - System-owned accounts without data are drained with a system transfer signed by
+    ///   the one of `signers` that derives to the account, or unsigned by the program if
+    ///   `signers` is empty because the account signed the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `system_program` is not the System program
+    /// - The account is owned by neither `program_id` nor the System program
+    ///   (`Errors::UnsupportedAccountOwner`)
+    /// - A system-owned account still holds data (`Errors::InvalidAccountData`)
+    /// - A system-owned account is not the PDA of any of `signers`
+    ///   (`Errors::WrongPdaAddress`)
+    /// - Unable to borrow account data or lamports
     /// - The SOL transfer fails
     pub fn close_account<'a>(
         from: &AccountInfo<'a>,
-        to: AccountInfo<'a>,
+        to: &AccountInfo<'a>,
         system_program: &AccountInfo<'a>,
+        program_id: &Pubkey,
+        signers: &[&PdaSigner],
     ) -> Result<(), Errors> {
+        Validators::assert_system_program(system_program.key)?;
         let amount = from.lamports();
-        let size = from.try_data_len()?;
-        sol_memset(&mut from.try_borrow_mut_data()?, 0, size);
-        Transfers::transfer_sol(from.clone(), to, system_program.clone(), amount)?;
-        from.assign(system_program.key);
-        Ok(())
+        if from.owner == program_id {
+            let size = from.try_data_len()?;
+            sol_memset(&mut from.try_borrow_mut_data()?, 0, size);
+            from.resize(0)?;
+            from.assign(system_program.key);
+            Transfers::transfer_sol_from_pda(&mut from.clone(), &mut to.clone(), amount)
+        } else if from.owner == system_program.key {
+            if !from.try_data_is_empty()? {
+                return Err(Errors::InvalidAccountData);
+            }
+            if signers.is_empty() {
+                return Transfers::transfer_sol(
+                    from.clone(),
+                    to.clone(),
+                    system_program.clone(),
+                    amount,
+                );
+            }
+            let signer = signers
+                .iter()
+                .find(|signer| signer.address() == from.key)
+                .ok_or(Errors::WrongPdaAddress)?;
+            Transfers::transfer_sol_signed(
+                from.clone(),
+                to.clone(),
+                system_program.clone(),
+                amount,
+                signer,
+            )
+        } else {
+            Err(Errors::UnsupportedAccountOwner)
+        }
     }
 
-    /// Closes a token account using the SPL token program.
+    /// Closes a token account using the token program that owns it (SPL Token or Token-2022).
+    ///
+    /// Pass the signing members in `multisig_signers` when `authority` is an SPL multisig,
+    /// or an empty slice for a single-key authority. Pass in `signers` every PDA of the
+    /// calling program that has to sign: `authority` itself, or the multisig members.
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The account is owned by neither SPL Token nor Token-2022
+    /// - The multisig signers don't satisfy the multisig threshold
     /// - Token instruction creation fails
     /// - Program invocation with seeds fails
     pub fn close_token_account<'a>(
         account: AccountInfo<'a>,
         destination: AccountInfo<'a>,
         authority: AccountInfo<'a>,
-        seeds: &[&[&[u8]]],
+        multisig_signers: &[AccountInfo<'a>],
+        signers: &[&PdaSigner],
     ) -> Result<(), Errors> {
-        let ix = spl_token::instruction::close_account(
-            &spl_token::ID,
+        Validators::assert_token_program(account.owner)?;
+        Validators::assert_multisig(&authority, multisig_signers)?;
+        let signer_keys: Vec<&Pubkey> = multisig_signers.iter().map(|signer| signer.key).collect();
+        let ix = spl_token_2022::instruction::close_account(
+            account.owner,
             account.key,
             destination.key,
             authority.key,
-            &[],
+            &signer_keys,
         )?;
-        solana_program::program::invoke_signed(&ix, &[account, destination, authority], seeds)?;
-        Ok(())
+        let mut account_infos = vec![account, destination, authority];
+        account_infos.extend_from_slice(multisig_signers);
+        PdaSigner::invoke(&ix, &account_infos, signers)
+    }
+
+    /// Empties and closes a token account in one call.
+    ///
+    /// Remaining tokens are transferred to `sweep_destination`, Token-2022 transfer fees
+    /// withheld in the account are harvested to the mint, and the account is closed with
+    /// its lamports going to `destination`. Native (wSOL) accounts skip the sweep, since
+    /// closing them unwraps the whole balance into `destination`.
+    ///
+    /// Pass the signing members in `multisig_signers` when `authority` is an SPL multisig,
+    /// or an empty slice for a single-key authority. Pass in `signers` every PDA of the
+    /// calling program that has to sign: `authority` itself, or the multisig members.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `token_program` is neither SPL Token nor Token-2022
+    /// - The multisig signers don't satisfy the multisig threshold
+    /// - `account` or `mint` can't be loaded as token state
+    /// - Token instruction creation fails
+    /// - Program invocation with seeds fails
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_token_account_with_sweep<'a>(
+        account: AccountInfo<'a>,
+        sweep_destination: AccountInfo<'a>,
+        mint: AccountInfo<'a>,
+        destination: AccountInfo<'a>,
+        authority: AccountInfo<'a>,
+        token_program: AccountInfo<'a>,
+        multisig_signers: &[AccountInfo<'a>],
+        signers: &[&PdaSigner],
+    ) -> Result<(), Errors> {
+        Validators::assert_token_program(token_program.key)?;
+        Validators::assert_multisig(&authority, multisig_signers)?;
+        let signer_keys: Vec<&Pubkey> = multisig_signers.iter().map(|signer| signer.key).collect();
+        let state = *TokenAccountLoader::load(&account)?
+            .assert_mint(mint.key)?
+            .state();
+
+        if !state.is_native() && state.amount > 0 {
+            let decimals = MintLoader::load(&mint)?.state().decimals;
+            let ix = spl_token_2022::instruction::transfer_checked(
+                token_program.key,
+                account.key,
+                mint.key,
+                sweep_destination.key,
+                authority.key,
+                &signer_keys,
+                state.amount,
+                decimals,
+            )?;
+            let mut account_infos = vec![
+                account.clone(),
+                mint.clone(),
+                sweep_destination,
+                authority.clone(),
+                token_program.clone(),
+            ];
+            account_infos.extend_from_slice(multisig_signers);
+            PdaSigner::invoke(&ix, &account_infos, signers)?;
+        }
+
+        if Self::withheld_amount(&account)? > 0 {
+            let ix = harvest_withheld_tokens_to_mint(token_program.key, mint.key, &[account.key])?;
+            solana_program::program::invoke(&ix, &[mint, account.clone(), token_program.clone()])?;
+        }
+
+        let ix = spl_token_2022::instruction::close_account(
+            token_program.key,
+            account.key,
+            destination.key,
+            authority.key,
+            &signer_keys,
+        )?;
+        let mut account_infos = vec![account, destination, authority, token_program];
+        account_infos.extend_from_slice(multisig_signers);
+        PdaSigner::invoke(&ix, &account_infos, signers)
+    }
+
+    /// Returns the transfer fees withheld in a Token-2022 account, or zero for accounts
+    /// without the transfer fee extension.
+    fn withheld_amount(account: &AccountInfo) -> Result<u64, Errors> {
+        if *account.owner != spl_token_2022::ID {
+            return Ok(0);
+        }
+        let data = account.try_borrow_data()?;
+        let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+        Ok(state
+            .get_extension::<TransferFeeAmount>()
+            .map_or(0, |fee| u64::from(fee.withheld_amount)))
+    }
+
+    /// Closes every account in `accounts`, typically the instruction's remaining accounts,
+    /// refunding all lamports to `recipient`.
+    ///
+    /// Each account is classified by owner and closed with the matching closer:
+    /// - Accounts owned by `program_id` are closed with `close_account` if `is_closable`
+    ///   accepts them. The predicate must at least check the discriminator of the account
+    ///   type being cleaned up and that the account has expired, otherwise any caller can
+    ///   drain the program's live accounts into `recipient`.
+    /// - Token accounts owned or closable by `authority` are closed with
+    ///   `close_token_account`, signed by `authority_signers`.
+    /// - System-owned accounts are drained with `close_account`, signed by the signer
+    ///   `system_signer` returns for their key, or unsigned if they signed the transaction.
+    ///
+    /// Accounts that can't be closed are skipped and reported rather than failing the batch.
+    /// Note that a failing CPI still aborts the whole instruction on-chain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `system_program` is not the System program
+    /// - The refund total overflows
+    /// - Any program invocation fails
+    #[allow(clippy::too_many_arguments)]
+    pub fn close_many<'a, C, F>(
+        accounts: &[AccountInfo<'a>],
+        recipient: &AccountInfo<'a>,
+        authority: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        program_id: &Pubkey,
+        authority_signers: &[&PdaSigner],
+        is_closable: C,
+        system_signer: F,
+    ) -> Result<CloseManyReport, Errors>
+    where
+        C: Fn(&AccountInfo<'a>) -> Result<bool, Errors>,
+        F: Fn(&Pubkey) -> Option<PdaSigner>,
+    {
+        Validators::assert_system_program(system_program.key)?;
+        let mut report = CloseManyReport::default();
+        for account in accounts {
+            let lamports = account.lamports();
+            let skip = if !account.is_writable {
+                Some(CloseSkipReason::NotWritable)
+            } else if lamports == 0 {
+                Some(CloseSkipReason::AlreadyClosed)
+            } else if account.key == recipient.key {
+                Some(CloseSkipReason::IsRecipient)
+            } else if account.owner == program_id {
+                match is_closable(account) {
+                    Ok(true) => {
+                        Self::close_account(account, recipient, system_program, program_id, &[])
+                            .err()
+                            .map(CloseSkipReason::Failed)
+                    }
+                    Ok(false) => Some(CloseSkipReason::NotClosable),
+                    Err(err) => Some(CloseSkipReason::Failed(err)),
+                }
+            } else if Validators::assert_token_program(account.owner).is_ok() {
+                Self::close_many_token_account(account, recipient, authority, authority_signers)
+            } else if account.owner == system_program.key {
+                Self::close_many_system_account(
+                    account,
+                    recipient,
+                    system_program,
+                    program_id,
+                    &system_signer,
+                )
+            } else {
+                Some(CloseSkipReason::UnsupportedOwner)
+            };
+            if let Some(reason) = skip {
+                report.skipped.push((*account.key, reason));
+            } else {
+                report.closed.push(*account.key);
+                report.refunded = report
+                    .refunded
+                    .checked_add(lamports)
+                    .ok_or(Errors::NumericalOverflow)?;
+            }
+        }
+        Ok(report)
+    }
+
+    fn close_many_token_account<'a>(
+        account: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        authority: &AccountInfo<'a>,
+        signers: &[&PdaSigner],
+    ) -> Option<CloseSkipReason> {
+        let state = match TokenAccountLoader::load(account) {
+            Ok(loader) => *loader.state(),
+            Err(err) => return Some(CloseSkipReason::Failed(err)),
+        };
+        let close_authority = state.close_authority.unwrap_or(state.owner);
+        if close_authority != *authority.key {
+            return Some(CloseSkipReason::WrongAuthority);
+        }
+        match Self::withheld_amount(account) {
+            Ok(withheld) if withheld > 0 => return Some(CloseSkipReason::NonEmptyTokenAccount),
+            Ok(_) => {}
+            Err(err) => return Some(CloseSkipReason::Failed(err)),
+        }
+        if !state.is_native() && state.amount > 0 {
+            return Some(CloseSkipReason::NonEmptyTokenAccount);
+        }
+        Self::close_token_account(
+            account.clone(),
+            recipient.clone(),
+            authority.clone(),
+            &[],
+            signers,
+        )
+        .err()
+        .map(CloseSkipReason::Failed)
+    }
+
+    fn close_many_system_account<'a, F>(
+        account: &AccountInfo<'a>,
+        recipient: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        program_id: &Pubkey,
+        system_signer: &F,
+    ) -> Option<CloseSkipReason>
+    where
+        F: Fn(&Pubkey) -> Option<PdaSigner>,
+    {
+        if !account.data_is_empty() {
+            return Some(CloseSkipReason::SystemAccountHasData);
+        }
+        let signer = system_signer(account.key);
+        if signer.is_none() && !account.is_signer {
+            return Some(CloseSkipReason::MissingSignerSeeds);
+        }
+        Self::close_account(
+            account,
+            recipient,
+            system_program,
+            program_id,
+            &signer.iter().collect::<Vec<_>>(),
+        )
+        .err()
+        .map(CloseSkipReason::Failed)
     }
 }
@@ -0,0 +1,607 @@
+use crate::errors::Errors;
+use crate::helpers::fixed::BoundedVec;
+use bytemuck::Pod;
+use solana_program::account_info::AccountInfo;
+use std::cell::{Ref, RefMut};
+use std::marker::PhantomData;
+use std::mem::size_of;
+use std::ops::{Deref, DerefMut};
+
+/// Size in bytes of a little-endian `u32` length or index header.
+const HEADER: usize = 4;
+
+/// A `Vec`-like list of up to `N` items.
+///
+/// Layout: `u32` length, then `N` item slots, the unused ones zeroed. For items whose
+/// Borsh encoding is their bytes, such as integers and `Pubkey`, the used part matches
+/// the Borsh encoding of a `BoundedVec<T, N>`, so a `BoundedVec` that is the *last*
+/// field of an account can be edited in place. Borsh doesn't pad a `BoundedVec`, so one
+/// followed by other fields can't: convert with `to_bounded_vec` and `assign` instead.
+pub struct AccountVec<T, const N: usize, D> {
+    data: D,
+    item: PhantomData<T>,
+}
+
+/// A map of up to `N` entries kept sorted by key, looked up by binary search.
+///
+/// Layout: `u32` length, then `N` slots of key followed by value.
+pub struct SortedMap<K, V, const N: usize, D> {
+    data: D,
+    entry: PhantomData<(K, V)>,
+}
+
+/// A set of `BITS` flags, bit `i` being bit `i % 8` of byte `i / 8`.
+///
+/// Layout: `BITS.div_ceil(8)` bytes, no header.
+pub struct Bitmap<const BITS: usize, D> {
+    data: D,
+}
+
+/// A queue of the last `N` items pushed, overwriting the oldest item when full.
+///
+/// Layout: `u32` index of the oldest item, `u32` length, then `N` item slots.
+pub struct RingBuffer<T, const N: usize, D> {
+    data: D,
+    item: PhantomData<T>,
+}
+
+fn read<T: Pod>(data: &[u8], offset: usize) -> T {
+    bytemuck::pod_read_unaligned(&data[offset..offset + size_of::<T>()])
+}
+
+fn write<T: Pod>(data: &mut [u8], offset: usize, value: &T) {
+    data[offset..offset + size_of::<T>()].copy_from_slice(bytemuck::bytes_of(value));
+}
+
+fn read_u32(data: &[u8], offset: usize) -> usize {
+    read::<u32>(data, offset) as usize
+}
+
+fn write_u32(data: &mut [u8], offset: usize, value: usize) {
+    #[allow(clippy::cast_possible_truncation)]
+    write(data, offset, &(value as u32).to_le_bytes());
+}
+
+const fn check_space(data: &[u8], space: usize) -> Result<(), Errors> {
+    if data.len() < space {
+        return Err(Errors::InvalidAccountData);
+    }
+    Ok(())
+}
+
+fn region<'a>(
+    account: &'a AccountInfo,
+    offset: usize,
+    len: usize,
+) -> Result<Ref<'a, [u8]>, Errors> {
+    let data = account.try_borrow_data()?;
+    Ref::filter_map(data, |data| data.get(offset..offset.checked_add(len)?))
+        .map_err(|_| Errors::InvalidAccountData)
+}
+
+fn region_mut<'a>(
+    account: &'a AccountInfo,
+    offset: usize,
+    len: usize,
+) -> Result<RefMut<'a, [u8]>, Errors> {
+    let data = account.try_borrow_mut_data()?;
+    RefMut::filter_map(data, |data| data.get_mut(offset..offset.checked_add(len)?))
+        .map_err(|_| Errors::InvalidAccountData)
+}
+
+impl<T: Pod, const N: usize, D: Deref<Target = [u8]>> AccountVec<T, N, D> {
+    /// Bytes the list takes in the account, the `MAX_SPACE` of `BoundedVec<T, N>`.
+    pub const SPACE: usize = HEADER + N * size_of::<T>();
+
+    /// Creates a view over `data`, which must start with the list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidAccountData` if `data` is shorter than `SPACE` or the
+    /// length header exceeds `N`.
+    pub fn new(data: D) -> Result<Self, Errors> {
+        check_space(&data, Self::SPACE)?;
+        if read_u32(&data, 0) > N {
+            return Err(Errors::InvalidAccountData);
+        }
+        Ok(Self {
+            data,
+            item: PhantomData,
+        })
+    }
+
+    /// Returns the number of items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        read_u32(&self.data, 0)
+    }
+
+    /// Returns `true` if the list holds no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of items.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the item at `index`, or `None` if out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| read(&self.data, Self::offset(index)))
+    }
+
+    /// Iterates over the items in order.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len()).map(|index| read(&self.data, Self::offset(index)))
+    }
+
+    /// Copies the items out into a `BoundedVec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if the length exceeds `N`, which `new`
+    /// already rules out.
+    pub fn to_bounded_vec(&self) -> Result<BoundedVec<T, N>, Errors> {
+        BoundedVec::from_vec(self.iter().collect())
+    }
+
+    const fn offset(index: usize) -> usize {
+        HEADER + index * size_of::<T>()
+    }
+}
+
+impl<T: Pod, const N: usize, D: DerefMut<Target = [u8]>> AccountVec<T, N, D> {
+    /// Replaces the item at `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below the length.
+    pub fn set(&mut self, index: usize, item: T) -> Result<(), Errors> {
+        if index >= self.len() {
+            return Err(Errors::IndexOutOfBounds);
+        }
+        write(&mut self.data, Self::offset(index), &item);
+        Ok(())
+    }
+
+    /// Appends an item.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if the list is full.
+    pub fn push(&mut self, item: T) -> Result<(), Errors> {
+        let len = self.len();
+        if len >= N {
+            return Err(Errors::FixedCapacityExceeded);
+        }
+        write(&mut self.data, Self::offset(len), &item);
+        write_u32(&mut self.data, 0, len + 1);
+        Ok(())
+    }
+
+    /// Removes and returns the last item.
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len().checked_sub(1)?;
+        let item = read(&self.data, Self::offset(len));
+        self.truncate(len);
+        Some(item)
+    }
+
+    /// Removes and returns the item at `index`, shifting the following items down.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below the length.
+    pub fn remove(&mut self, index: usize) -> Result<T, Errors> {
+        let len = self.len();
+        if index >= len {
+            return Err(Errors::IndexOutOfBounds);
+        }
+        let item = read(&self.data, Self::offset(index));
+        self.data.copy_within(
+            Self::offset(index + 1)..Self::offset(len),
+            Self::offset(index),
+        );
+        self.truncate(len - 1);
+        Ok(item)
+    }
+
+    /// Removes and returns the item at `index`, moving the last item into its slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below the length.
+    pub fn swap_remove(&mut self, index: usize) -> Result<T, Errors> {
+        let len = self.len();
+        if index >= len {
+            return Err(Errors::IndexOutOfBounds);
+        }
+        let item = read(&self.data, Self::offset(index));
+        let last: T = read(&self.data, Self::offset(len - 1));
+        write(&mut self.data, Self::offset(index), &last);
+        self.truncate(len - 1);
+        Ok(item)
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Replaces the items with those of `items`.
+    pub fn assign(&mut self, items: &BoundedVec<T, N>) {
+        self.clear();
+        for (index, item) in items.iter().enumerate() {
+            write(&mut self.data, Self::offset(index), item);
+        }
+        write_u32(&mut self.data, 0, items.len());
+    }
+
+    fn truncate(&mut self, len: usize) {
+        let end = Self::offset(self.len());
+        self.data[Self::offset(len)..end].fill(0);
+        write_u32(&mut self.data, 0, len);
+    }
+}
+
+impl<K: Pod + Ord, V: Pod, const N: usize, D: Deref<Target = [u8]>> SortedMap<K, V, N, D> {
+    /// Bytes the map takes in the account.
+    pub const SPACE: usize = HEADER + N * (size_of::<K>() + size_of::<V>());
+
+    /// Creates a view over `data`, which must start with the map.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidAccountData` if `data` is shorter than `SPACE`, the
+    /// length header exceeds `N` or the keys are not strictly increasing.
+    pub fn new(data: D) -> Result<Self, Errors> {
+        check_space(&data, Self::SPACE)?;
+        if read_u32(&data, 0) > N {
+            return Err(Errors::InvalidAccountData);
+        }
+        let map = Self {
+            data,
+            entry: PhantomData,
+        };
+        let sorted = (1..map.len()).all(|index| map.key(index - 1) < map.key(index));
+        if !sorted {
+            return Err(Errors::InvalidAccountData);
+        }
+        Ok(map)
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        read_u32(&self.data, 0)
+    }
+
+    /// Returns `true` if the map holds no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the maximum number of entries.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the value stored for `key`.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        let index = self.search(key).ok()?;
+        Some(read(&self.data, Self::offset(index) + size_of::<K>()))
+    }
+
+    /// Returns `true` if the map holds `key`.
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.search(key).is_ok()
+    }
+
+    /// Iterates over the entries in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (K, V)> + '_ {
+        (0..self.len()).map(|index| {
+            let offset = Self::offset(index);
+            (
+                read(&self.data, offset),
+                read(&self.data, offset + size_of::<K>()),
+            )
+        })
+    }
+
+    fn key(&self, index: usize) -> K {
+        read(&self.data, Self::offset(index))
+    }
+
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        let (mut low, mut high) = (0, self.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match self.key(mid).cmp(key) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    const fn offset(index: usize) -> usize {
+        HEADER + index * (size_of::<K>() + size_of::<V>())
+    }
+}
+
+impl<K: Pod + Ord, V: Pod, const N: usize, D: DerefMut<Target = [u8]>> SortedMap<K, V, N, D> {
+    /// Inserts or replaces the value for `key`, returning the replaced value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if `key` is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, Errors> {
+        let len = self.len();
+        let value_offset = |index| Self::offset(index) + size_of::<K>();
+        match self.search(&key) {
+            Ok(index) => {
+                let old = read(&self.data, value_offset(index));
+                write(&mut self.data, value_offset(index), &value);
+                Ok(Some(old))
+            }
+            Err(_) if len >= N => Err(Errors::FixedCapacityExceeded),
+            Err(index) => {
+                self.data.copy_within(
+                    Self::offset(index)..Self::offset(len),
+                    Self::offset(index + 1),
+                );
+                write(&mut self.data, Self::offset(index), &key);
+                write(&mut self.data, value_offset(index), &value);
+                write_u32(&mut self.data, 0, len + 1);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Removes `key`, returning its value.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.search(key).ok()?;
+        let len = self.len();
+        let value = read(&self.data, Self::offset(index) + size_of::<K>());
+        self.data.copy_within(
+            Self::offset(index + 1)..Self::offset(len),
+            Self::offset(index),
+        );
+        self.data[Self::offset(len - 1)..Self::offset(len)].fill(0);
+        write_u32(&mut self.data, 0, len - 1);
+        Some(value)
+    }
+}
+
+impl<const BITS: usize, D: Deref<Target = [u8]>> Bitmap<BITS, D> {
+    /// Bytes the bitmap takes in the account.
+    pub const SPACE: usize = BITS.div_ceil(8);
+
+    /// Creates a view over `data`, which must start with the bitmap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidAccountData` if `data` is shorter than `SPACE`.
+    pub fn new(data: D) -> Result<Self, Errors> {
+        check_space(&data, Self::SPACE)?;
+        Ok(Self { data })
+    }
+
+    /// Returns the number of bits.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        BITS
+    }
+
+    /// Returns whether bit `index` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below `BITS`.
+    pub fn get(&self, index: usize) -> Result<bool, Errors> {
+        let (byte, mask) = Self::position(index)?;
+        Ok(self.data[byte] & mask != 0)
+    }
+
+    /// Returns the number of set bits.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.data[..Self::SPACE]
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    const fn position(index: usize) -> Result<(usize, u8), Errors> {
+        if index >= BITS {
+            return Err(Errors::IndexOutOfBounds);
+        }
+        Ok((index / 8, 1 << (index % 8)))
+    }
+}
+
+impl<const BITS: usize, D: DerefMut<Target = [u8]>> Bitmap<BITS, D> {
+    /// Sets bit `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below `BITS`.
+    pub fn set(&mut self, index: usize) -> Result<(), Errors> {
+        let (byte, mask) = Self::position(index)?;
+        self.data[byte] |= mask;
+        Ok(())
+    }
+
+    /// Clears bit `index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below `BITS`.
+    pub fn unset(&mut self, index: usize) -> Result<(), Errors> {
+        let (byte, mask) = Self::position(index)?;
+        self.data[byte] &= !mask;
+        Ok(())
+    }
+
+    /// Sets bit `index`, returning whether it was already set.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::IndexOutOfBounds` if `index` is not below `BITS`.
+    pub fn test_and_set(&mut self, index: usize) -> Result<bool, Errors> {
+        let (byte, mask) = Self::position(index)?;
+        let was_set = self.data[byte] & mask != 0;
+        self.data[byte] |= mask;
+        Ok(was_set)
+    }
+}
+
+impl<T: Pod, const N: usize, D: Deref<Target = [u8]>> RingBuffer<T, N, D> {
+    /// Bytes the buffer takes in the account.
+    pub const SPACE: usize = 2 * HEADER + N * size_of::<T>();
+
+    /// Creates a view over `data`, which must start with the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidAccountData` if `data` is shorter than `SPACE` or the
+    /// headers exceed `N`.
+    pub fn new(data: D) -> Result<Self, Errors> {
+        check_space(&data, Self::SPACE)?;
+        let (head, len) = (read_u32(&data, 0), read_u32(&data, HEADER));
+        if len > N || (head >= N && N > 0) || (N == 0 && head > 0) {
+            return Err(Errors::InvalidAccountData);
+        }
+        Ok(Self {
+            data,
+            item: PhantomData,
+        })
+    }
+
+    /// Returns the number of items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        read_u32(&self.data, HEADER)
+    }
+
+    /// Returns `true` if the buffer holds no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the next push overwrites the oldest item.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Returns the maximum number of items.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the item at `index`, counting from the oldest.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| read(&self.data, self.offset(index)))
+    }
+
+    /// Iterates over the items from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        (0..self.len()).map(|index| read(&self.data, self.offset(index)))
+    }
+
+    fn head(&self) -> usize {
+        read_u32(&self.data, 0)
+    }
+
+    fn offset(&self, index: usize) -> usize {
+        2 * HEADER + (self.head() + index) % N * size_of::<T>()
+    }
+}
+
+impl<T: Pod, const N: usize, D: DerefMut<Target = [u8]>> RingBuffer<T, N, D> {
+    /// Appends an item, returning the oldest item if it was overwritten.
+    pub fn push(&mut self, item: T) -> Option<T> {
+        if N == 0 {
+            return Some(item);
+        }
+        let len = self.len();
+        if len < N {
+            let offset = self.offset(len);
+            write(&mut self.data, offset, &item);
+            write_u32(&mut self.data, HEADER, len + 1);
+            return None;
+        }
+        let offset = self.offset(0);
+        let oldest = read(&self.data, offset);
+        write(&mut self.data, offset, &item);
+        let head = (self.head() + 1) % N;
+        write_u32(&mut self.data, 0, head);
+        Some(oldest)
+    }
+
+    /// Removes and returns the oldest item.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let offset = self.offset(0);
+        let oldest = read(&self.data, offset);
+        self.data[offset..offset + size_of::<T>()].fill(0);
+        let head = (self.head() + 1) % N;
+        write_u32(&mut self.data, 0, head);
+        write_u32(&mut self.data, HEADER, len - 1);
+        Some(oldest)
+    }
+
+    /// Removes all items.
+    pub fn clear(&mut self) {
+        self.data[..Self::SPACE].fill(0);
+    }
+}
+
+macro_rules! impl_account_loaders {
+    ([$($generics:tt)*] $name:ident<$($arg:tt),*>) => {
+        impl<'a, $($generics)*> $name<$($arg,)* Ref<'a, [u8]>> {
+            /// Borrows the collection stored at `offset` in the account data.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the account data is already mutably borrowed, is too
+            /// short, or doesn't hold a valid collection.
+            pub fn load(account: &'a AccountInfo, offset: usize) -> Result<Self, Errors> {
+                Self::new(region(account, offset, Self::SPACE)?)
+            }
+        }
+
+        impl<'a, $($generics)*> $name<$($arg,)* RefMut<'a, [u8]>> {
+            /// Mutably borrows the collection stored at `offset` in the account data.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the account data is already borrowed, is too short,
+            /// or doesn't hold a valid collection.
+            pub fn load_mut(account: &'a AccountInfo, offset: usize) -> Result<Self, Errors> {
+                Self::new(region_mut(account, offset, Self::SPACE)?)
+            }
+        }
+    };
+}
+
+impl_account_loaders!([T: Pod, const N: usize] AccountVec<T, N>);
+impl_account_loaders!([K: Pod + Ord, V: Pod, const N: usize] SortedMap<K, V, N>);
+impl_account_loaders!([const BITS: usize] Bitmap<BITS>);
+impl_account_loaders!([T: Pod, const N: usize] RingBuffer<T, N>);
@@ -1,3 +1,5 @@
+use crate::errors::Errors;
+use crate::helpers::u256::U256;
 use num_bigint::BigInt;
 
 /// Provides utilities for converting between different data formats.
@@ -29,6 +31,24 @@ impl Conversions {
             .fold(BigInt::from(0), |acc, &num| (acc << 8) | BigInt::from(num))
     }
 
+    /// Converts a byte array of at most 32 bytes to a `U256`, without allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - The big-endian byte array to convert
+    /// * `reverse` - Whether the array is little-endian instead
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NumericalOverflow` if `arr` is longer than 32 bytes.
+    pub fn number_array_to_u256(arr: &[u8], reverse: bool) -> Result<U256, Errors> {
+        if reverse {
+            U256::from_le_slice(arr)
+        } else {
+            U256::from_be_slice(arr)
+        }
+    }
+
     /// Converts a vector to a fixed-size 32-byte array.
     ///
     /// If the vector is shorter than 32 bytes, the remaining bytes are zero-filled.
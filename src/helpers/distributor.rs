@@ -0,0 +1,358 @@
+use crate::errors::Errors;
+use crate::helpers::claims::ClaimBitmap;
+use crate::helpers::generic::Generic;
+use crate::helpers::loaders::{MintLoader, TokenAccountLoader};
+use crate::helpers::merkle::Merkle;
+use crate::helpers::pda_signer::PdaSigner;
+use crate::helpers::space::AccountSpace;
+use crate::helpers::transfers::Transfers;
+use crate::helpers::validators::Validators;
+use crate::helpers::versioned::{AccountHeader, AccountSchema};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+/// State of a merkle distributor, stored after an `AccountHeader`.
+#[derive(AccountSpace, BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[account_space(header = AccountHeader)]
+pub struct DistributorState {
+    /// Key the distributor address is derived from.
+    pub base: Pubkey,
+    /// Key allowed to claw back unclaimed tokens.
+    pub authority: Pubkey,
+    /// Mint of the distributed tokens.
+    pub mint: Pubkey,
+    /// Associated token account of the distributor holding the tokens.
+    pub vault: Pubkey,
+    /// Root of the merkle tree of `(index, claimant, amount)` leaves.
+    pub root: [u8; 32],
+    /// Unix timestamp from which claims are closed and clawback is open.
+    pub expires_at: i64,
+    /// Total amount claimed so far.
+    pub total_claimed: u64,
+    /// Bump of the distributor address.
+    pub bump: u8,
+}
+
+/// Parameters of a new distributor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DistributorParams {
+    /// Key the distributor address is derived from, e.g. a fresh keypair's.
+    pub base: Pubkey,
+    /// Key allowed to claw back unclaimed tokens.
+    pub authority: Pubkey,
+    /// Root of the merkle tree of `MerkleDistributor::leaf` leaves.
+    pub root: [u8; 32],
+    /// Unix timestamp from which claims are closed and clawback is open.
+    pub expires_at: i64,
+}
+
+/// The accounts every distributor operation takes.
+pub struct DistributorAccounts<'a, 'info> {
+    /// The distributor PDA.
+    pub distributor: &'a AccountInfo<'info>,
+    /// The distributor's associated token account for `mint`.
+    pub vault: &'a AccountInfo<'info>,
+    /// Mint of the distributed tokens.
+    pub mint: &'a AccountInfo<'info>,
+    /// SPL Token or Token-2022, whichever owns `mint`.
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+/// A claim of `amount` tokens by the leaf at `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClaimArgs<'p> {
+    /// Index of the leaf, which is also its bit in the claim bitmap.
+    pub index: u64,
+    /// Amount of tokens in the leaf.
+    pub amount: u64,
+    /// Merkle proof of the leaf.
+    pub proof: &'p [[u8; 32]],
+}
+
+/// A token airdrop paid from a PDA-owned vault to the leaves of a merkle tree.
+///
+/// The distributor lives at the PDA of `["distributor", base]` and owns the vault, its
+/// associated token account. Each leaf is `leaf(index, claimant, amount)`; a claim is
+/// signed by the claimant, proven against the root and recorded in a `ClaimBitmap`
+/// namespaced by the distributor, whose chunks are created with `claims`. Once
+/// `expires_at` passes, claims close and the authority can claw back the rest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MerkleDistributor<const BITS: usize = 8192> {
+    program_id: Pubkey,
+    merkle: Merkle,
+}
+
+impl<const BITS: usize> MerkleDistributor<BITS> {
+    /// Schema of the distributor account.
+    pub const SCHEMA: AccountSchema = AccountSchema::new("MerkleDistributor", &[]);
+
+    /// Creates a distributor of `program_id` whose trees use `merkle`.
+    #[must_use]
+    pub const fn new(program_id: Pubkey, merkle: Merkle) -> Self {
+        Self { program_id, merkle }
+    }
+
+    /// Returns the distributor address and bump for `base`.
+    #[must_use]
+    pub fn address(&self, base: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"distributor", base.as_ref()], &self.program_id)
+    }
+
+    /// Returns the leaf hash of a claim, for building the tree off-chain.
+    #[must_use]
+    pub fn leaf(&self, index: u64, claimant: &Pubkey, amount: u64) -> [u8; 32] {
+        self.merkle
+            .hash_leaf(&Self::leaf_data(index, claimant, amount))
+    }
+
+    /// Returns the leaf data of a claim: its index, claimant and amount.
+    fn leaf_data(index: u64, claimant: &Pubkey, amount: u64) -> Vec<u8> {
+        [
+            &index.to_le_bytes()[..],
+            claimant.as_ref(),
+            &amount.to_le_bytes(),
+        ]
+        .concat()
+    }
+
+    /// Returns the claim bitmap of the distributor at `distributor`.
+    #[must_use]
+    pub const fn claims(&self, distributor: &Pubkey) -> ClaimBitmap<BITS> {
+        ClaimBitmap::new(self.program_id, *distributor)
+    }
+
+    /// Creates the distributor account and its vault, paid by `payer`.
+    ///
+    /// The vault is created idempotently, so tokens may be sent to it beforehand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A program account isn't the expected program (`Errors::InvalidProgramId`)
+    /// - `distributor` is not the PDA of `params.base` or already exists
+    /// - `vault` is not the distributor's associated token account for `mint`
+    ///   (`Errors::OwnerMismatch`)
+    /// - Account creation fails
+    pub fn create<'a, 'info>(
+        &self,
+        accounts: &DistributorAccounts<'a, 'info>,
+        payer: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        associated_token_program: &'a AccountInfo<'info>,
+        params: &DistributorParams,
+    ) -> Result<(), Errors> {
+        Validators::assert_token_program(accounts.token_program.key)?;
+        Validators::assert_associated_token_program(associated_token_program.key)?;
+        let vault = get_associated_token_address_with_program_id(
+            accounts.distributor.key,
+            accounts.mint.key,
+            accounts.token_program.key,
+        );
+        if *accounts.vault.key != vault {
+            return Err(Errors::OwnerMismatch);
+        }
+
+        let signer = PdaSigner::derive(&self.program_id, &[b"distributor", params.base.as_ref()]);
+        Generic::create_pda_account_with_signer(
+            accounts.distributor,
+            system_program,
+            payer,
+            DistributorState::max_space(),
+            &signer,
+        )?;
+        let state = DistributorState {
+            base: params.base,
+            authority: params.authority,
+            mint: *accounts.mint.key,
+            vault,
+            root: params.root,
+            expires_at: params.expires_at,
+            total_claimed: 0,
+            bump: signer.bump(),
+        };
+        let body = borsh::to_vec(&state).map_err(|_| Errors::InvalidAccountData)?;
+        Self::SCHEMA.initialize(accounts.distributor, &body)?;
+
+        PdaSigner::invoke(
+            &spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                payer.key,
+                accounts.distributor.key,
+                accounts.mint.key,
+                accounts.token_program.key,
+            ),
+            &[
+                payer.clone(),
+                accounts.vault.clone(),
+                accounts.distributor.clone(),
+                accounts.mint.clone(),
+                system_program.clone(),
+                accounts.token_program.clone(),
+                associated_token_program.clone(),
+            ],
+            &[],
+        )
+    }
+
+    /// Loads the distributor state, checking the account is a distributor of the program.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account isn't owned by the program (`Errors::InvalidAccountOwner`)
+    /// - The data isn't a distributor (see `AccountSchema::body`), or can't be
+    ///   deserialized (`Errors::InvalidAccountData`)
+    /// - The account is not the PDA recorded in its state (`Errors::WrongPdaAddress`)
+    pub fn load(&self, distributor: &AccountInfo) -> Result<DistributorState, Errors> {
+        self.load_signed(distributor).map(|(state, _)| state)
+    }
+
+    /// Pays the claim in `args` from the vault to `recipient` and marks it claimed.
+    ///
+    /// `claim_chunk` is the chunk of `claims` holding `args.index`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `claimant` didn't sign (`Errors::MissingRequiredSignature`)
+    /// - The distributor or its vault don't match (see `load`, `Errors::WrongPdaAddress`)
+    /// - `mint` isn't the distributor's mint (`Errors::MintMismatch`)
+    /// - The distributor has expired (`Errors::DistributorExpired`)
+    /// - The proof is invalid (`Errors::InvalidMerkleProof`)
+    /// - The index is already claimed (`Errors::AlreadyClaimed`) or `claim_chunk` is wrong
+    /// - The token transfer fails
+    pub fn claim<'a, 'info>(
+        &self,
+        accounts: &DistributorAccounts<'a, 'info>,
+        claim_chunk: &'a AccountInfo<'info>,
+        claimant: &'a AccountInfo<'info>,
+        recipient: &'a AccountInfo<'info>,
+        args: &ClaimArgs,
+    ) -> Result<(), Errors> {
+        if !claimant.is_signer {
+            return Err(Errors::MissingRequiredSignature);
+        }
+        let (mut state, signer) = self.load_signed(accounts.distributor)?;
+        Self::assert_vault(&state, accounts)?;
+        if Clock::get()?.unix_timestamp >= state.expires_at {
+            return Err(Errors::DistributorExpired);
+        }
+
+        let decimals = MintLoader::load(accounts.mint)?.state().decimals;
+        let leaf_data = Self::leaf_data(args.index, claimant.key, args.amount);
+        self.merkle
+            .verify(&[&leaf_data], args.index, args.proof, &state.root)?;
+        self.claims(accounts.distributor.key)
+            .claim(claim_chunk, args.index)?;
+
+        Transfers::transfer_token_from_pda(
+            accounts.vault.clone(),
+            accounts.mint.clone(),
+            recipient.clone(),
+            accounts.token_program.clone(),
+            accounts.distributor.clone(),
+            args.amount,
+            decimals,
+            &[],
+            &[&signer],
+        )?;
+        state.total_claimed = state
+            .total_claimed
+            .checked_add(args.amount)
+            .ok_or(Errors::NumericalOverflow)?;
+        Self::store(accounts.distributor, &state)
+    }
+
+    /// Moves every unclaimed token from the vault to `destination` once the distributor
+    /// has expired, returning the amount moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `authority` isn't the distributor authority (`Errors::OwnerMismatch`) or didn't
+    ///   sign (`Errors::MissingRequiredSignature`)
+    /// - The distributor or its vault don't match (see `load`, `Errors::WrongPdaAddress`)
+    /// - `mint` isn't the distributor's mint (`Errors::MintMismatch`)
+    /// - The distributor hasn't expired yet (`Errors::DistributorNotExpired`)
+    /// - The token transfer fails
+    pub fn clawback<'a, 'info>(
+        &self,
+        accounts: &DistributorAccounts<'a, 'info>,
+        authority: &'a AccountInfo<'info>,
+        destination: &'a AccountInfo<'info>,
+    ) -> Result<u64, Errors> {
+        let (state, signer) = self.load_signed(accounts.distributor)?;
+        if *authority.key != state.authority {
+            return Err(Errors::OwnerMismatch);
+        }
+        if !authority.is_signer {
+            return Err(Errors::MissingRequiredSignature);
+        }
+        Self::assert_vault(&state, accounts)?;
+        if Clock::get()?.unix_timestamp < state.expires_at {
+            return Err(Errors::DistributorNotExpired);
+        }
+
+        let amount = TokenAccountLoader::load(accounts.vault)?.state().amount;
+        if amount > 0 {
+            let decimals = MintLoader::load(accounts.mint)?.state().decimals;
+            Transfers::transfer_token_from_pda(
+                accounts.vault.clone(),
+                accounts.mint.clone(),
+                destination.clone(),
+                accounts.token_program.clone(),
+                accounts.distributor.clone(),
+                amount,
+                decimals,
+                &[],
+                &[&signer],
+            )?;
+        }
+        Ok(amount)
+    }
+
+    fn load_signed(
+        &self,
+        distributor: &AccountInfo,
+    ) -> Result<(DistributorState, PdaSigner), Errors> {
+        if *distributor.owner != self.program_id {
+            return Err(Errors::InvalidAccountOwner);
+        }
+        let state = {
+            let data = distributor.try_borrow_data()?;
+            DistributorState::try_from_slice(Self::SCHEMA.body(&data)?)
+                .map_err(|_| Errors::InvalidAccountData)?
+        };
+        let signer = PdaSigner::with_bump(
+            &self.program_id,
+            &[b"distributor", state.base.as_ref()],
+            state.bump,
+        )?;
+        signer.verify(distributor)?;
+        Ok((state, signer))
+    }
+
+    fn assert_vault(
+        state: &DistributorState,
+        accounts: &DistributorAccounts,
+    ) -> Result<(), Errors> {
+        if *accounts.vault.key != state.vault {
+            return Err(Errors::WrongPdaAddress);
+        }
+        if *accounts.mint.key != state.mint {
+            return Err(Errors::MintMismatch);
+        }
+        Ok(())
+    }
+
+    fn store(distributor: &AccountInfo, state: &DistributorState) -> Result<(), Errors> {
+        let mut data = distributor.try_borrow_mut_data()?;
+        let body = data
+            .get_mut(AccountHeader::LEN..)
+            .ok_or(Errors::InvalidAccountData)?;
+        borsh::to_writer(body, state).map_err(|_| Errors::InvalidAccountData)
+    }
+}
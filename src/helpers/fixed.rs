@@ -0,0 +1,362 @@
+use crate::errors::Errors;
+use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::{Pack, Sealed};
+use std::io::{Read, Write};
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Size in bytes of the little-endian `u32` length prefix.
+const LEN_PREFIX: usize = 4;
+
+/// Up to `N` bytes stored in exactly `4 + N` bytes: a little-endian `u32` length
+/// followed by the bytes, zero-padded to `N`.
+///
+/// Pack, Borsh and zero-copy (`bytemuck`) share the same layout, so the serialized size
+/// never depends on the content. Decoding rejects non-zero padding, so each value has a
+/// single encoding.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedBytes<const N: usize> {
+    len: [u8; LEN_PREFIX],
+    data: [u8; N],
+}
+
+impl<const N: usize> FixedBytes<N> {
+    /// Serialized size in bytes.
+    pub const LEN: usize = LEN_PREFIX + N;
+
+    /// Creates an empty value.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            len: [0; LEN_PREFIX],
+            data: [0; N],
+        }
+    }
+
+    /// Copies `bytes` into a new value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if `bytes` is longer than `N`.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, Errors> {
+        if bytes.len() > N {
+            return Err(Errors::FixedCapacityExceeded);
+        }
+        let len = u32::try_from(bytes.len()).map_err(|_| Errors::FixedCapacityExceeded)?;
+        let mut value = Self::new();
+        value.len = len.to_le_bytes();
+        value.data[..bytes.len()].copy_from_slice(bytes);
+        Ok(value)
+    }
+
+    /// Returns the maximum number of bytes the value can hold.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the stored length, which exceeds `N` only for corrupted zero-copy data.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        u32::from_le_bytes(self.len) as usize
+    }
+
+    /// Returns `true` if the value holds no bytes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the stored bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if the stored length exceeds `N`,
+    /// which can only happen for corrupted zero-copy data.
+    pub fn as_bytes(&self) -> Result<&[u8], Errors> {
+        self.data
+            .get(..self.len())
+            .ok_or(Errors::FixedCapacityExceeded)
+    }
+
+    fn write_to(&self, dst: &mut [u8]) {
+        dst[..LEN_PREFIX].copy_from_slice(&self.len);
+        dst[LEN_PREFIX..Self::LEN].copy_from_slice(&self.data);
+    }
+
+    fn read_from(src: &[u8]) -> Result<Self, Errors> {
+        let mut value = Self::new();
+        value.len.copy_from_slice(&src[..LEN_PREFIX]);
+        value.data.copy_from_slice(&src[LEN_PREFIX..Self::LEN]);
+        value.validated()
+    }
+
+    /// Checks that the length fits and the padding after it is zeroed.
+    fn validated(self) -> Result<Self, Errors> {
+        let len = self.as_bytes()?.len();
+        if self.data[len..].iter().any(|&byte| byte != 0) {
+            return Err(Errors::InvalidAccountData);
+        }
+        Ok(self)
+    }
+}
+
+impl<const N: usize> Default for FixedBytes<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for FixedBytes<N> {
+    type Error = Errors;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(bytes)
+    }
+}
+
+// SAFETY: `repr(C)` over byte arrays has alignment 1 and no padding, and the all-zero
+// value is the empty value.
+unsafe impl<const N: usize> Zeroable for FixedBytes<N> {}
+// SAFETY: as above; every bit pattern is a valid value since accessors check the length.
+unsafe impl<const N: usize> Pod for FixedBytes<N> {}
+
+impl<const N: usize> Sealed for FixedBytes<N> {}
+
+impl<const N: usize> Pack for FixedBytes<N> {
+    const LEN: usize = LEN_PREFIX + N;
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        self.write_to(dst);
+    }
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        Self::read_from(src).map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl<const N: usize> BorshSerialize for FixedBytes<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.len)?;
+        writer.write_all(&self.data)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedBytes<N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut value = Self::new();
+        reader.read_exact(&mut value.len)?;
+        reader.read_exact(&mut value.data)?;
+        value.validated().map_err(invalid_data)
+    }
+}
+
+/// A UTF-8 string of up to `N` bytes with the layout of `FixedBytes<N>`.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FixedString<const N: usize> {
+    bytes: FixedBytes<N>,
+}
+
+impl<const N: usize> FixedString<N> {
+    /// Serialized size in bytes.
+    pub const LEN: usize = FixedBytes::<N>::LEN;
+
+    /// Creates an empty string.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: FixedBytes::new(),
+        }
+    }
+
+    /// Returns the maximum length of the string in bytes.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the stored length in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the string is empty.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if, for corrupted zero-copy data, the stored length exceeds `N`
+    /// (`Errors::FixedCapacityExceeded`) or the bytes are not UTF-8 (`Errors::InvalidUtf8`).
+    pub fn as_str(&self) -> Result<&str, Errors> {
+        std::str::from_utf8(self.bytes.as_bytes()?).map_err(|_| Errors::InvalidUtf8)
+    }
+
+    fn validated(bytes: FixedBytes<N>) -> Result<Self, Errors> {
+        let value = Self { bytes };
+        value.as_str()?;
+        Ok(value)
+    }
+}
+
+impl<const N: usize> FromStr for FixedString<N> {
+    type Err = Errors;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            bytes: FixedBytes::from_slice(value.as_bytes())?,
+        })
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for FixedString<N> {
+    type Error = Errors;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+// SAFETY: `repr(transparent)` over `FixedBytes<N>`, which is `Zeroable`.
+unsafe impl<const N: usize> Zeroable for FixedString<N> {}
+// SAFETY: `repr(transparent)` over `FixedBytes<N>`, which is `Pod`; `as_str` validates UTF-8.
+unsafe impl<const N: usize> Pod for FixedString<N> {}
+
+impl<const N: usize> Sealed for FixedString<N> {}
+
+impl<const N: usize> Pack for FixedString<N> {
+    const LEN: usize = FixedBytes::<N>::LEN;
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        self.bytes.write_to(dst);
+    }
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        FixedBytes::read_from(src)
+            .and_then(Self::validated)
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl<const N: usize> BorshSerialize for FixedString<N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.bytes.serialize(writer)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for FixedString<N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        Self::validated(FixedBytes::deserialize_reader(reader)?).map_err(invalid_data)
+    }
+}
+
+/// A `Vec` holding at most `N` items, Borsh-encoded like a `Vec`: a little-endian `u32`
+/// length followed by the items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedVec<T, const N: usize> {
+    items: Vec<T>,
+}
+
+impl<T, const N: usize> BoundedVec<T, N> {
+    /// Creates an empty vector.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Wraps `items`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if there are more than `N` items.
+    pub fn from_vec(items: Vec<T>) -> Result<Self, Errors> {
+        if items.len() > N {
+            return Err(Errors::FixedCapacityExceeded);
+        }
+        Ok(Self { items })
+    }
+
+    /// Returns the maximum number of items.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Appends an item.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FixedCapacityExceeded` if the vector is full.
+    pub fn try_push(&mut self, item: T) -> Result<(), Errors> {
+        if self.items.len() >= N {
+            return Err(Errors::FixedCapacityExceeded);
+        }
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// Removes and returns the last item.
+    pub fn pop(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Returns the items.
+    #[must_use]
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for BoundedVec<T, N> {
+    type Error = Errors;
+
+    fn try_from(items: Vec<T>) -> Result<Self, Self::Error> {
+        Self::from_vec(items)
+    }
+}
+
+impl<T: BorshSerialize, const N: usize> BorshSerialize for BoundedVec<T, N> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.items.serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize, const N: usize> BorshDeserialize for BoundedVec<T, N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+        if len > N {
+            return Err(invalid_data(Errors::FixedCapacityExceeded));
+        }
+        let items = (0..len)
+            .map(|_| T::deserialize_reader(reader))
+            .collect::<std::io::Result<Vec<T>>>()?;
+        Ok(Self { items })
+    }
+}
+
+fn invalid_data(err: Errors) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+}
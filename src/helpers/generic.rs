@@ -1,9 +1,15 @@
 use crate::errors::Errors;
+use crate::helpers::pda_signer::PdaSigner;
+use crate::helpers::rent_payer::RentPayer;
+use crate::helpers::space::AccountSpace;
+use crate::helpers::validators::Validators;
+use crate::helpers::versioned::AccountHeader;
 use sha2::{Digest, Sha256};
 use solana_program::account_info::AccountInfo;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::log::sol_log;
 use solana_program::native_token::LAMPORTS_PER_SOL;
+use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::rent::Rent;
 use solana_program::sysvar::Sysvar;
@@ -28,9 +34,15 @@ impl Generic {
 
     /// Burns tokens from a token account using program-derived address authority.
     ///
+    /// Pass the signing members in `multisig_signers` when `authority` is an SPL multisig,
+    /// or an empty slice for a single-key authority. Pass in `signers` every PDA of the
+    /// calling program that has to sign: `authority` itself, or the multisig members.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - `token_program` is neither SPL Token nor Token-2022
+    /// - The multisig signers don't satisfy the multisig threshold
     /// - Token instruction creation fails
     /// - Program invocation with seeds fails
     pub fn burn_tokens<'a>(
@@ -39,22 +51,23 @@ impl Generic {
         mint: AccountInfo<'a>,
         authority: AccountInfo<'a>,
         amount: u64,
-        seeds: &[&[&[u8]]],
+        multisig_signers: &[AccountInfo<'a>],
+        signers: &[&PdaSigner],
     ) -> Result<(), Errors> {
-        let ix = spl_token::instruction::burn(
+        Validators::assert_token_program(token_program.key)?;
+        Validators::assert_multisig(&authority, multisig_signers)?;
+        let signer_keys: Vec<&Pubkey> = multisig_signers.iter().map(|signer| signer.key).collect();
+        let ix = spl_token_2022::instruction::burn(
             token_program.key,
             account.key,
             mint.key,
             authority.key,
-            &[authority.key],
+            &signer_keys,
             amount,
         )?;
-        solana_program::program::invoke_signed(
-            &ix,
-            &[account, mint, authority, token_program],
-            seeds,
-        )?;
-        Ok(())
+        let mut account_infos = vec![account, mint, authority, token_program];
+        account_infos.extend_from_slice(multisig_signers);
+        PdaSigner::invoke(&ix, &account_infos, signers)
     }
 
     /// Prints a SOL value in human-readable format to the program log.
@@ -86,6 +99,10 @@ impl Generic {
     /// # Returns
     ///
     /// A cloned `AccountInfo` with `'static` lifetime
+    #[deprecated(
+        since = "0.1.3",
+        note = "unsound; borrow accounts through `AccountContext` to keep their real lifetime"
+    )]
     #[must_use]
     pub fn unsafe_clone_account_info(input: &AccountInfo<'_>) -> AccountInfo<'static> {
         unsafe { std::mem::transmute::<AccountInfo, AccountInfo>(input.clone()) }
@@ -114,11 +131,15 @@ impl Generic {
     ///
     /// Validates that the target account matches the expected PDA derived from the seeds,
     /// then creates the account with the specified space and assigns it to the program.
+    /// A PDA that was pre-funded by a third party is topped up, allocated and assigned
+    /// instead, so sending lamports to the address can't block its creation.
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - `system_program` is not the System program
     /// - The target account key doesn't match the expected PDA
+    /// - The target account already has data or a non-system owner
     /// - Rent calculation fails
     /// - Account creation fails
     pub fn create_pda_account<'a, 'info>(
@@ -129,47 +150,140 @@ impl Generic {
         program_id: &Pubkey,
         seeds: &[&[u8]],
     ) -> Result<(), Errors> {
-        let (expected_pda, bump) = Pubkey::find_program_address(seeds, program_id);
-        if *target_account.key != expected_pda {
-            return Err(Errors::WrongPdaAddress);
+        Self::create_pda_account_with_signer(
+            target_account,
+            system_program,
+            payer,
+            space,
+            &PdaSigner::derive(program_id, seeds),
+        )
+    }
+
+    /// Creates a PDA account like `create_pda_account` from an already derived `signer`,
+    /// assigning it to the signer's program, so callers that also need the bump derive
+    /// the address only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `system_program` is not the System program
+    /// - The target account key isn't the signer's PDA (`Errors::WrongPdaAddress`)
+    /// - The target account already has data or a non-system owner
+    /// - Rent calculation fails
+    /// - Account creation fails
+    pub fn create_pda_account_with_signer<'a, 'info>(
+        target_account: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        payer: &'a AccountInfo<'info>,
+        space: usize,
+        signer: &PdaSigner,
+    ) -> Result<(), Errors> {
+        Validators::assert_system_program(system_program.key)?;
+        signer.verify(target_account)?;
+        if !Self::is_unused(target_account)? {
+            return Err(Errors::AccountAlreadyInUse);
         }
-        let bump: &[u8] = &[bump];
-        let mut combined_seeds = Vec::with_capacity(seeds.len() + 1);
-        combined_seeds.extend_from_slice(seeds);
-        combined_seeds.push(bump);
-        let seeds = combined_seeds.as_slice();
 
         let lamports = Rent::get()?.minimum_balance(space);
-        let ix = solana_program::system_instruction::create_account(
-            payer.key,
-            target_account.key,
+        Self::create_or_allocate(
+            target_account,
+            space,
             lamports,
-            space as u64,
-            program_id,
-        );
-
-        solana_program::program::invoke_signed(
-            &ix,
-            &[
-                payer.clone(),
-                target_account.clone(),
-                system_program.clone(),
-            ],
-            &[seeds],
+            signer.program_id(),
+            payer,
+            system_program,
+            &[signer],
         )?;
         Ok(())
     }
 
-    #[inline(always)]
+    /// Creates a PDA account like `create_pda_account`, starting it with an
+    /// `AccountHeader` that records `payer` as its rent payer. `space` must include the
+    /// header.
+    ///
+    /// Pair with `Closers::close_pda_to_rent_payer` so the rent can only be refunded to
+    /// the account that paid it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `space` is smaller than the header (`Errors::InvalidAccountData`)
+    /// - `create_pda_account` fails
+    /// - Unable to borrow account data to write the header
+    pub fn create_pda_account_with_rent_payer<'a, 'info>(
+        target_account: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        payer: &'a AccountInfo<'info>,
+        space: usize,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+    ) -> Result<(), Errors> {
+        if space < AccountHeader::LEN {
+            return Err(Errors::InvalidAccountData);
+        }
+        Self::create_pda_account(
+            target_account,
+            system_program,
+            payer,
+            space,
+            program_id,
+            seeds,
+        )?;
+        RentPayer::write(target_account, program_id, payer.key)
+    }
+
+    /// Creates a PDA account sized for any value of `T`, see `create_pda_account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `create_pda_account` fails.
+    pub fn create_pda_account_for<'a, 'info, T: AccountSpace>(
+        target_account: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        payer: &'a AccountInfo<'info>,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+    ) -> Result<(), Errors> {
+        Self::create_pda_account(
+            target_account,
+            system_program,
+            payer,
+            T::max_space(),
+            program_id,
+            seeds,
+        )
+    }
+
+    /// Creates an account funded with the rent-exempt minimum for `space`.
+    ///
+    /// If the account already holds lamports, tops it up to the rent-exempt minimum,
+    /// then allocates and assigns it to `owner` instead of calling `create_account`.
+    /// Pass `signers` when `account` is a PDA of the calling program.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `system_program` is not the System program (`ProgramError::IncorrectProgramId`)
+    /// - The account already has data or a non-system owner
+    ///   (`ProgramError::AccountAlreadyInitialized`)
+    /// - Rent calculation fails
+    /// - Any of the system program invocations fail
+    #[inline]
     pub fn create_account_with_minimal_balance<'a, 'info>(
         account: &'a AccountInfo<'info>,
         space: usize,
         owner: &Pubkey,
         payer: &'a AccountInfo<'info>,
         system_program: &'a AccountInfo<'info>,
-        signers_seeds: &[&[&[u8]]],
+        signers: &[&PdaSigner],
         rent_sysvar: Option<&'a AccountInfo<'info>>,
     ) -> ProgramResult {
+        if Validators::assert_system_program(system_program.key).is_err() {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        if !Self::is_unused(account)? {
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
         let lamports = if let Some(rent_sysvar) = rent_sysvar {
             let rent = Rent::from_account_info(rent_sysvar)?;
             rent.minimum_balance(space)
@@ -177,6 +291,40 @@ impl Generic {
             Rent::get()?.minimum_balance(space)
         };
 
+        Self::create_or_allocate(
+            account,
+            space,
+            lamports,
+            owner,
+            payer,
+            system_program,
+            signers,
+        )
+    }
+
+    /// Returns whether `account` is still a system account without data, so it can be
+    /// created, or allocated and assigned.
+    fn is_unused(account: &AccountInfo) -> Result<bool, ProgramError> {
+        Ok(
+            *account.owner == solana_system_interface::program::ID
+                && account.try_data_is_empty()?,
+        )
+    }
+
+    /// Creates `account` with `create_account`, or, when it already holds lamports,
+    /// tops it up to `lamports` and issues `allocate` and `assign` signed by the account.
+    fn create_or_allocate<'a, 'info>(
+        account: &'a AccountInfo<'info>,
+        space: usize,
+        lamports: u64,
+        owner: &Pubkey,
+        payer: &'a AccountInfo<'info>,
+        system_program: &'a AccountInfo<'info>,
+        signers: &[&PdaSigner],
+    ) -> ProgramResult {
+        let seeds = PdaSigner::seeds_of(signers);
+        let signers_seeds: Vec<&[&[u8]]> = seeds.iter().map(Vec::as_slice).collect();
+
         if account.lamports() == 0 {
             let ix = solana_program::system_instruction::create_account(
                 payer.key,
@@ -185,33 +333,38 @@ impl Generic {
                 space as u64,
                 owner,
             );
-            solana_program::program::invoke_signed(
+            return solana_program::program::invoke_signed(
                 &ix,
                 &[payer.clone(), account.clone(), system_program.clone()],
-                signers_seeds,
-            )?;
-        } else {
-            let required_lamports = lamports.saturating_sub(account.lamports());
-
-            if required_lamports > 0 {
-                let ix =
-                    solana_program::system_instruction::transfer(payer.key, account.key, required_lamports);
-                solana_program::program::invoke(
-                    &ix,
-                    &[payer.clone(), account.clone(), system_program.clone()],
-                )?;
-            }
-
-            let ix = solana_program::system_instruction::assign(account.key, owner);
-            solana_program::program::invoke_signed(
+                &signers_seeds,
+            );
+        }
+
+        let required_lamports = lamports.saturating_sub(account.lamports());
+        if required_lamports > 0 {
+            let ix = solana_program::system_instruction::transfer(
+                payer.key,
+                account.key,
+                required_lamports,
+            );
+            solana_program::program::invoke(
                 &ix,
-                &[account.clone(), system_program.clone()],
-                signers_seeds,
+                &[payer.clone(), account.clone(), system_program.clone()],
             )?;
-
-            account.resize(space)?;
         }
 
-        Ok(())
+        let ix = solana_program::system_instruction::allocate(account.key, space as u64);
+        solana_program::program::invoke_signed(
+            &ix,
+            &[account.clone(), system_program.clone()],
+            &signers_seeds,
+        )?;
+
+        let ix = solana_program::system_instruction::assign(account.key, owner);
+        solana_program::program::invoke_signed(
+            &ix,
+            &[account.clone(), system_program.clone()],
+            &signers_seeds,
+        )
     }
 }
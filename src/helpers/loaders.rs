@@ -0,0 +1,177 @@
+use crate::errors::Errors;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_option::COption;
+use solana_program::program_pack::{IsInitialized, Pack};
+use solana_program::pubkey::Pubkey;
+use spl_token::state::{Account, AccountState, Mint};
+use spl_token_2022::extension::{BaseState, StateWithExtensions};
+
+/// Unpacks the base SPL token state of an account owned by SPL Token or Token-2022.
+///
+/// Token-2022 accounts go through `StateWithExtensions` with `U`, the Token-2022 twin of
+/// `T`, which rejects multisig-sized data, a mismatched account type byte and non-zero
+/// padding before the base state is read.
+fn unpack_base<T: Pack + IsInitialized, U: BaseState + Pack>(
+    info: &AccountInfo,
+) -> Result<T, Errors> {
+    if *info.owner != spl_token::ID && *info.owner != spl_token_2022::ID {
+        return Err(Errors::InvalidAccountOwner);
+    }
+    let data = info.try_borrow_data()?;
+    let base = if *info.owner == spl_token_2022::ID {
+        StateWithExtensions::<U>::unpack(&data).map_err(|_| Errors::InvalidAccountData)?;
+        &data[..T::LEN]
+    } else {
+        &data[..]
+    };
+    T::unpack(base).map_err(|_| Errors::InvalidAccountData)
+}
+
+/// A token account unpacked from an `AccountInfo` owned by SPL Token or Token-2022.
+pub struct TokenAccountLoader<'a, 'info> {
+    info: &'a AccountInfo<'info>,
+    state: Account,
+}
+
+impl<'a, 'info> TokenAccountLoader<'a, 'info> {
+    /// Checks the owning token program and unpacks the token account state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account isn't owned by a token program (`Errors::InvalidAccountOwner`)
+    /// - The data isn't an initialized token account (`Errors::InvalidAccountData`)
+    pub fn load(info: &'a AccountInfo<'info>) -> Result<Self, Errors> {
+        let state = unpack_base::<_, spl_token_2022::state::Account>(info)?;
+        Ok(Self { info, state })
+    }
+
+    /// Returns the underlying `AccountInfo`.
+    #[must_use]
+    pub const fn info(&self) -> &'a AccountInfo<'info> {
+        self.info
+    }
+
+    /// Returns the unpacked token account state.
+    #[must_use]
+    pub const fn state(&self) -> &Account {
+        &self.state
+    }
+
+    /// Asserts the token account belongs to `mint`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::MintMismatch` if the account holds a different mint.
+    pub fn assert_mint(&self, mint: &Pubkey) -> Result<&Self, Errors> {
+        if self.state.mint != *mint {
+            return Err(Errors::MintMismatch);
+        }
+        Ok(self)
+    }
+
+    /// Asserts the token account is owned by `owner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::OwnerMismatch` if the account has a different owner.
+    pub fn assert_owner(&self, owner: &Pubkey) -> Result<&Self, Errors> {
+        if self.state.owner != *owner {
+            return Err(Errors::OwnerMismatch);
+        }
+        Ok(self)
+    }
+
+    /// Asserts the token account holds at least `amount` tokens.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InsufficientFunds` if the balance is below `amount`.
+    pub const fn assert_min_amount(&self, amount: u64) -> Result<&Self, Errors> {
+        if self.state.amount < amount {
+            return Err(Errors::InsufficientFunds);
+        }
+        Ok(self)
+    }
+
+    /// Asserts the token account is not frozen.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::AccountFrozen` if the account is frozen.
+    pub fn assert_not_frozen(&self) -> Result<&Self, Errors> {
+        if self.state.state == AccountState::Frozen {
+            return Err(Errors::AccountFrozen);
+        }
+        Ok(self)
+    }
+
+    /// Asserts the token account has no delegate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::UnexpectedDelegate` if a delegate is set.
+    pub fn assert_no_delegate(&self) -> Result<&Self, Errors> {
+        if self.state.delegate.is_some() {
+            return Err(Errors::UnexpectedDelegate);
+        }
+        Ok(self)
+    }
+}
+
+/// A mint unpacked from an `AccountInfo` owned by SPL Token or Token-2022.
+pub struct MintLoader<'a, 'info> {
+    info: &'a AccountInfo<'info>,
+    state: Mint,
+}
+
+impl<'a, 'info> MintLoader<'a, 'info> {
+    /// Checks the owning token program and unpacks the mint state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account isn't owned by a token program (`Errors::InvalidAccountOwner`)
+    /// - The data isn't an initialized mint (`Errors::InvalidAccountData`)
+    pub fn load(info: &'a AccountInfo<'info>) -> Result<Self, Errors> {
+        let state = unpack_base::<_, spl_token_2022::state::Mint>(info)?;
+        Ok(Self { info, state })
+    }
+
+    /// Returns the underlying `AccountInfo`.
+    #[must_use]
+    pub const fn info(&self) -> &'a AccountInfo<'info> {
+        self.info
+    }
+
+    /// Returns the unpacked mint state.
+    #[must_use]
+    pub const fn state(&self) -> &Mint {
+        &self.state
+    }
+
+    /// Asserts the mint authority is `authority`, or that the mint has no
+    /// authority when `authority` is `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::MintAuthorityMismatch` if the mint authority differs.
+    pub fn assert_mint_authority(&self, authority: Option<&Pubkey>) -> Result<&Self, Errors> {
+        if self.state.mint_authority != COption::from(authority.copied()) {
+            return Err(Errors::MintAuthorityMismatch);
+        }
+        Ok(self)
+    }
+
+    /// Asserts the mint uses `decimals` decimals.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::DecimalsMismatch` if the mint's decimals differ.
+    pub const fn assert_decimals(&self, decimals: u8) -> Result<&Self, Errors> {
+        if self.state.decimals != decimals {
+            return Err(Errors::DecimalsMismatch);
+        }
+        Ok(self)
+    }
+}
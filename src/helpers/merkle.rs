@@ -0,0 +1,185 @@
+use crate::errors::Errors;
+
+/// Prefix hashed in front of leaf data, so a leaf can't be mistaken for an inner node.
+pub const LEAF_PREFIX: &[u8] = &[0];
+/// Prefix hashed in front of a pair of child nodes.
+pub const NODE_PREFIX: &[u8] = &[1];
+/// Sibling of the last node of a level with an odd number of nodes.
+pub const EMPTY_NODE: [u8; 32] = [0; 32];
+
+/// Hash function of a merkle tree; both use the Solana hash syscalls on-chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MerkleHash {
+    /// SHA-256.
+    Sha256,
+    /// Keccak-256, as used by Ethereum tooling.
+    Keccak256,
+}
+
+/// How the two children of a node are ordered before hashing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofOrder {
+    /// The smaller child comes first, so proofs don't depend on the leaf index.
+    SortedPair,
+    /// The leaf index bits pick the side at each level, binding the proof to the index.
+    Indexed,
+}
+
+/// A merkle scheme: hash function plus child ordering.
+///
+/// Leaves are `hash(LEAF_PREFIX || data)` and inner nodes `hash(NODE_PREFIX || left ||
+/// right)`, which prevents second-preimage attacks that pass an inner node off as a
+/// leaf. A level with an odd number of nodes pairs its last node with `EMPTY_NODE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Merkle {
+    hash: MerkleHash,
+    order: ProofOrder,
+}
+
+impl Merkle {
+    /// Creates a scheme.
+    #[must_use]
+    pub const fn new(hash: MerkleHash, order: ProofOrder) -> Self {
+        Self { hash, order }
+    }
+
+    /// Hashes leaf data.
+    #[must_use]
+    pub fn hash_leaf(&self, data: &[u8]) -> [u8; 32] {
+        self.hash_leaf_parts(&[data])
+    }
+
+    /// Hashes leaf data given as concatenated parts, e.g. claimant, index and amount.
+    #[must_use]
+    pub fn hash_leaf_parts(&self, parts: &[&[u8]]) -> [u8; 32] {
+        let mut chunks = Vec::with_capacity(parts.len() + 1);
+        chunks.push(LEAF_PREFIX);
+        chunks.extend_from_slice(parts);
+        self.hashv(&chunks)
+    }
+
+    /// Hashes two children into their parent, `left` being the child at the even index.
+    #[must_use]
+    pub fn hash_nodes(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = match self.order {
+            ProofOrder::SortedPair if right < left => (right, left),
+            _ => (left, right),
+        };
+        self.hashv(&[NODE_PREFIX, left, right])
+    }
+
+    /// Computes the root reached from the leaf made of `leaf_parts` at `index` through the
+    /// sibling hashes in `proof`, ordered from the leaf up.
+    ///
+    /// The leaf is hashed here with `hash_leaf_parts`, so an inner node can't be passed
+    /// off as a leaf with a shortened proof.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidMerkleProof` if, for indexed proofs, `index` doesn't fit
+    /// in a tree as deep as the proof.
+    pub fn compute_root(
+        &self,
+        leaf_parts: &[&[u8]],
+        index: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<[u8; 32], Errors> {
+        if self.order == ProofOrder::Indexed && proof.len() < 64 && index >> proof.len() != 0 {
+            return Err(Errors::InvalidMerkleProof);
+        }
+        let mut node = self.hash_leaf_parts(leaf_parts);
+        for (level, sibling) in proof.iter().enumerate() {
+            let is_right = level < 64 && (index >> level) & 1 == 1;
+            node = if is_right {
+                self.hash_nodes(sibling, &node)
+            } else {
+                self.hash_nodes(&node, sibling)
+            };
+        }
+        Ok(node)
+    }
+
+    /// Verifies that the leaf made of `leaf_parts` at `index` belongs to the tree with
+    /// `root`, see `compute_root`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidMerkleProof` if the proof doesn't lead to `root`.
+    pub fn verify(
+        &self,
+        leaf_parts: &[&[u8]],
+        index: u64,
+        proof: &[[u8; 32]],
+        root: &[u8; 32],
+    ) -> Result<(), Errors> {
+        if self.compute_root(leaf_parts, index, proof)? != *root {
+            return Err(Errors::InvalidMerkleProof);
+        }
+        Ok(())
+    }
+
+    fn hashv(self, chunks: &[&[u8]]) -> [u8; 32] {
+        match self.hash {
+            MerkleHash::Sha256 => solana_program::hash::hashv(chunks).to_bytes(),
+            MerkleHash::Keccak256 => solana_keccak_hasher::hashv(chunks).to_bytes(),
+        }
+    }
+}
+
+/// Off-chain merkle tree builder producing roots and proofs for a `Merkle` scheme.
+#[derive(Debug, Clone)]
+pub struct MerkleTree {
+    merkle: Merkle,
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Builds the tree over leaf hashes, see `Merkle::hash_leaf`.
+    #[must_use]
+    pub fn new(merkle: Merkle, leaves: Vec<[u8; 32]>) -> Self {
+        let mut levels = vec![leaves];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = &levels[levels.len() - 1];
+            let parent = level
+                .chunks(2)
+                .map(|pair| merkle.hash_nodes(&pair[0], pair.get(1).unwrap_or(&EMPTY_NODE)))
+                .collect();
+            levels.push(parent);
+        }
+        Self { merkle, levels }
+    }
+
+    /// Returns the scheme of the tree.
+    #[must_use]
+    pub const fn merkle(&self) -> &Merkle {
+        &self.merkle
+    }
+
+    /// Returns the root, or `EMPTY_NODE` for a tree without leaves.
+    #[must_use]
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.levels.len() - 1]
+            .first()
+            .copied()
+            .unwrap_or(EMPTY_NODE)
+    }
+
+    /// Returns the proof of the leaf at `index`, or `None` if out of bounds.
+    #[must_use]
+    pub fn proof(&self, index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+        let proof = self.levels[..self.levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(depth, level)| {
+                level
+                    .get((index >> depth) ^ 1)
+                    .copied()
+                    .unwrap_or(EMPTY_NODE)
+            })
+            .collect();
+        Some(proof)
+    }
+}
@@ -1,10 +1,44 @@
+/// Lifetime-safe access to the accounts of the current instruction.
+pub mod account_context;
+/// Claim-tracking bitmap spread across chunked PDAs.
+pub mod claims;
 /// Utilities for closing accounts and transferring their lamports.
 pub mod closers;
+/// Zero-copy, fixed-capacity collections living inside account data.
+///
+/// Each collection is a view over a byte buffer `D`: `&[u8]`/`&mut [u8]`, or the
+/// `Ref<[u8]>`/`RefMut<[u8]>` returned by `load`/`load_mut`, which borrow a region of
+/// an `AccountInfo`'s data. Items are `Pod` types read and written unaligned, so a
+/// collection can start at any offset. Lengths are little-endian `u32` headers, and a
+/// header that doesn't fit the capacity is rejected when the view is created.
+pub mod collections;
 /// Utilities for converting between different data formats.
 pub mod conversions;
+/// Fixed-capacity string, byte and vector types for account state.
+pub mod fixed;
+/// Merkle distributor for token airdrops with expiry and clawback.
+pub mod distributor;
 /// Generic utility functions for Solana programs.
 pub mod generic;
+/// Typed, validated loaders for SPL token accounts and mints.
+pub mod loaders;
+/// Merkle proof verification and off-chain tree building.
+pub mod merkle;
+/// Typed PDA signer accepted by the CPI helpers.
+pub mod pda_signer;
+/// Rent payer recorded in the `AccountHeader`, for refunds only to the payer.
+pub mod rent_payer;
+/// Typed, length-checked PDA seed schemas and hashed long seeds.
+pub mod seeds;
+/// Serialized size of account types.
+pub mod space;
 /// Utilities for transferring SOL and SPL tokens.
 pub mod transfers;
+/// Fixed-width 256-bit unsigned integer for on-chain arithmetic.
+pub mod u256;
 /// Validation utilities for Solana programs.
 pub mod validators;
+/// System-owned PDA vaults holding SOL.
+pub mod vaults;
+/// Versioned account headers and in-place schema migration.
+pub mod versioned;
@@ -0,0 +1,106 @@
+use crate::errors::Errors;
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+
+/// The seeds and bump a program signs a CPI with on behalf of one of its PDAs.
+///
+/// Built once from the program id and seed parts, it carries the bump alongside the
+/// seeds so CPI helpers can't be handed seeds that are missing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdaSigner {
+    program_id: Pubkey,
+    seeds: Vec<Vec<u8>>,
+    bump: [u8; 1],
+    address: Pubkey,
+}
+
+impl PdaSigner {
+    /// Derives the PDA and canonical bump for `seeds` under `program_id`.
+    #[must_use]
+    pub fn derive(program_id: &Pubkey, seeds: &[&[u8]]) -> Self {
+        let (address, bump) = Pubkey::find_program_address(seeds, program_id);
+        Self {
+            program_id: *program_id,
+            seeds: seeds.iter().map(|seed| seed.to_vec()).collect(),
+            bump: [bump],
+            address,
+        }
+    }
+
+    /// Builds the signer from seeds and a known bump, e.g. one stored in account state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidSeeds` if the seeds and bump don't form a valid PDA.
+    pub fn with_bump(program_id: &Pubkey, seeds: &[&[u8]], bump: u8) -> Result<Self, Errors> {
+        let mut signer_seeds = seeds.to_vec();
+        let bump_seed = [bump];
+        signer_seeds.push(&bump_seed);
+        let address = Pubkey::create_program_address(&signer_seeds, program_id)
+            .map_err(|_| Errors::InvalidSeeds)?;
+        Ok(Self {
+            program_id: *program_id,
+            seeds: seeds.iter().map(|seed| seed.to_vec()).collect(),
+            bump: bump_seed,
+            address,
+        })
+    }
+
+    /// Returns the PDA this signer signs for.
+    #[must_use]
+    pub const fn address(&self) -> &Pubkey {
+        &self.address
+    }
+
+    /// Returns the bump seed.
+    #[must_use]
+    pub const fn bump(&self) -> u8 {
+        self.bump[0]
+    }
+
+    /// Returns the program the PDA is derived from.
+    #[must_use]
+    pub const fn program_id(&self) -> &Pubkey {
+        &self.program_id
+    }
+
+    /// Verifies `account` is the PDA this signer signs for.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::WrongPdaAddress` if the account key differs.
+    pub fn verify(&self, account: &AccountInfo) -> Result<(), Errors> {
+        if *account.key != self.address {
+            return Err(Errors::WrongPdaAddress);
+        }
+        Ok(())
+    }
+
+    /// Returns the seeds followed by the bump, as expected by `invoke_signed`.
+    #[must_use]
+    pub fn signer_seeds(&self) -> Vec<&[u8]> {
+        self.seeds
+            .iter()
+            .map(Vec::as_slice)
+            .chain(std::iter::once(self.bump.as_slice()))
+            .collect()
+    }
+
+    /// Returns the signer seeds of each of `signers`, as expected by `invoke_signed`.
+    pub(crate) fn seeds_of<'s>(signers: &[&'s Self]) -> Vec<Vec<&'s [u8]>> {
+        signers.iter().map(|signer| signer.signer_seeds()).collect()
+    }
+
+    /// Invokes `instruction`, signing with every PDA in `signers`.
+    pub(crate) fn invoke(
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers: &[&Self],
+    ) -> Result<(), Errors> {
+        let seeds = Self::seeds_of(signers);
+        let signers_seeds: Vec<&[&[u8]]> = seeds.iter().map(Vec::as_slice).collect();
+        solana_program::program::invoke_signed(instruction, account_infos, &signers_seeds)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::errors::Errors;
+use crate::helpers::versioned::AccountHeader;
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+
+/// Records who paid an account's rent in the `rent_payer` field of its `AccountHeader`,
+/// so the rent can only be refunded to them.
+///
+/// Only accounts owned by the program are trusted: anyone can write a header into an
+/// account they own.
+pub struct RentPayer;
+
+impl RentPayer {
+    /// Records `payer` as the rent payer in the account header, keeping the rest of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account isn't owned by `program_id` (`Errors::InvalidAccountOwner`)
+    /// - The account data is shorter than the header or the header is malformed
+    ///   (`Errors::InvalidAccountData`)
+    /// - Unable to borrow account data
+    pub fn write(account: &AccountInfo, program_id: &Pubkey, payer: &Pubkey) -> Result<(), Errors> {
+        Self::assert_owner(account, program_id)?;
+        let mut data = account.try_borrow_mut_data()?;
+        AccountHeader {
+            rent_payer: Some(*payer),
+            ..AccountHeader::read(&data)?
+        }
+        .write(&mut data)
+    }
+
+    /// Reads the rent payer recorded in the account header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account isn't owned by `program_id` (`Errors::InvalidAccountOwner`)
+    /// - The account data is shorter than the header or the header is malformed
+    ///   (`Errors::InvalidAccountData`)
+    /// - The header doesn't record a rent payer (`Errors::RentPayerNotRecorded`)
+    /// - Unable to borrow account data
+    pub fn read(account: &AccountInfo, program_id: &Pubkey) -> Result<Pubkey, Errors> {
+        Self::assert_owner(account, program_id)?;
+        let data = account.try_borrow_data()?;
+        AccountHeader::read(&data)?
+            .rent_payer
+            .ok_or(Errors::RentPayerNotRecorded)
+    }
+
+    /// Asserts `payer` is the rent payer recorded in the account header.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::RentPayerMismatch` if a different payer is recorded, or any error
+    /// of `read`.
+    pub fn assert_payer(
+        account: &AccountInfo,
+        program_id: &Pubkey,
+        payer: &Pubkey,
+    ) -> Result<(), Errors> {
+        if Self::read(account, program_id)? != *payer {
+            return Err(Errors::RentPayerMismatch);
+        }
+        Ok(())
+    }
+
+    fn assert_owner(account: &AccountInfo, program_id: &Pubkey) -> Result<(), Errors> {
+        if account.owner != program_id {
+            return Err(Errors::InvalidAccountOwner);
+        }
+        Ok(())
+    }
+}
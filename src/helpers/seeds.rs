@@ -0,0 +1,239 @@
+use crate::errors::Errors;
+use crate::helpers::pda_signer::PdaSigner;
+use solana_program::pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN};
+
+/// One part of a PDA seed schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedPart {
+    /// A constant byte string, e.g. an account type prefix.
+    Const(&'static [u8]),
+    /// A 32-byte public key.
+    Pubkey,
+    /// A `u64` encoded as 8 little-endian bytes.
+    U64,
+    /// Exactly this many caller-provided bytes.
+    Bytes(usize),
+    /// An input of any length, hashed into 32 bytes with `HashedSeed::hash`.
+    Hashed,
+}
+
+impl SeedPart {
+    /// Returns the length in bytes of the seed this part produces.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Const(bytes) => bytes.len(),
+            Self::Pubkey => 32,
+            Self::U64 => 8,
+            Self::Bytes(len) => *len,
+            Self::Hashed => HashedSeed::LEN,
+        }
+    }
+
+    /// Returns `true` if the part produces an empty seed.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A value filling one of the non-constant parts of a seed schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedValue<'a> {
+    /// Fills a `SeedPart::Pubkey`.
+    Pubkey(&'a Pubkey),
+    /// Fills a `SeedPart::U64`.
+    U64(u64),
+    /// Fills a `SeedPart::Bytes` of the same length.
+    Bytes(&'a [u8]),
+    /// Fills a `SeedPart::Hashed` with an input of any length.
+    Hashed(&'a [u8]),
+}
+
+/// Turns inputs of any length, such as names, URLs or composite keys, into 32-byte seeds.
+///
+/// The scheme is stable so clients in any language can derive the same PDA:
+/// - `hash(input)` is `sha256(input)`.
+/// - `hash_parts([p0, .., pn])` is `sha256(len(p0) || p0 || .. || len(pn) || pn)`, with
+///   each length a little-endian `u32`, so `["ab", "c"]` and `["a", "bc"]` differ.
+///
+/// On-chain the digest is computed with the `sol_sha256` syscall, off-chain with `sha2`.
+pub struct HashedSeed;
+
+impl HashedSeed {
+    /// Length in bytes of a hashed seed.
+    pub const LEN: usize = 32;
+
+    /// Hashes a single input into a seed.
+    #[must_use]
+    pub fn hash(input: &[u8]) -> [u8; Self::LEN] {
+        Self::sha256(&[input])
+    }
+
+    /// Hashes the length-prefixed parts of a composite key into a seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a part is longer than `u32::MAX` bytes.
+    #[must_use]
+    pub fn hash_parts(parts: &[&[u8]]) -> [u8; Self::LEN] {
+        let lengths: Vec<[u8; 4]> = parts
+            .iter()
+            .map(|part| {
+                u32::try_from(part.len())
+                    .expect("seed part longer than u32::MAX bytes")
+                    .to_le_bytes()
+            })
+            .collect();
+        let chunks: Vec<&[u8]> = lengths
+            .iter()
+            .zip(parts)
+            .flat_map(|(length, part)| [length.as_slice(), part])
+            .collect();
+        Self::sha256(&chunks)
+    }
+
+    #[cfg(target_os = "solana")]
+    fn sha256(chunks: &[&[u8]]) -> [u8; Self::LEN] {
+        solana_program::hash::hashv(chunks).to_bytes()
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    fn sha256(chunks: &[&[u8]]) -> [u8; Self::LEN] {
+        use sha2::{Digest, Sha256};
+
+        let mut hasher = Sha256::new();
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        hasher.finalize().into()
+    }
+}
+
+/// The seeds of a PDA, declared once and shared by the program and its clients.
+///
+/// Declaring the schema as a `const` checks the runtime limits at compile time:
+///
+/// ```
+/// use solana_helpers::helpers::seeds::{SeedPart, SeedSchema};
+///
+/// const VAULT_SEEDS: SeedSchema<3> =
+///     SeedSchema::new([SeedPart::Const(b"vault"), SeedPart::Pubkey, SeedPart::U64]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedSchema<const N: usize> {
+    parts: [SeedPart; N],
+}
+
+impl<const N: usize> SeedSchema<N> {
+    /// Creates a schema from its parts, in seed order.
+    ///
+    /// # Panics
+    ///
+    /// Panics, at compile time when evaluated in a `const`, if the schema has more than
+    /// `MAX_SEEDS - 1` parts (one seed is reserved for the bump) or a part is longer than
+    /// `MAX_SEED_LEN` bytes.
+    #[must_use]
+    pub const fn new(parts: [SeedPart; N]) -> Self {
+        assert!(
+            N < MAX_SEEDS,
+            "too many seeds: one of the 16 is reserved for the bump"
+        );
+        let mut index = 0;
+        while index < N {
+            assert!(
+                parts[index].len() <= MAX_SEED_LEN,
+                "seed is longer than 32 bytes"
+            );
+            index += 1;
+        }
+        Self { parts }
+    }
+
+    /// Returns the parts of the schema.
+    #[must_use]
+    pub const fn parts(&self) -> &[SeedPart; N] {
+        &self.parts
+    }
+
+    /// Builds the seeds, taking one value for each non-constant part in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::SeedSchemaMismatch` if the values don't match the non-constant
+    /// parts in number, kind or length.
+    pub fn seeds(&self, values: &[SeedValue]) -> Result<Vec<Vec<u8>>, Errors> {
+        let mut values = values.iter();
+        let mut seeds = Vec::with_capacity(N);
+        for part in &self.parts {
+            let seed = match part {
+                SeedPart::Const(bytes) => bytes.to_vec(),
+                SeedPart::Pubkey => match values.next() {
+                    Some(SeedValue::Pubkey(key)) => key.to_bytes().to_vec(),
+                    _ => return Err(Errors::SeedSchemaMismatch),
+                },
+                SeedPart::U64 => match values.next() {
+                    Some(SeedValue::U64(value)) => value.to_le_bytes().to_vec(),
+                    _ => return Err(Errors::SeedSchemaMismatch),
+                },
+                SeedPart::Bytes(len) => match values.next() {
+                    Some(SeedValue::Bytes(bytes)) if bytes.len() == *len => bytes.to_vec(),
+                    _ => return Err(Errors::SeedSchemaMismatch),
+                },
+                SeedPart::Hashed => match values.next() {
+                    Some(SeedValue::Hashed(input)) => HashedSeed::hash(input).to_vec(),
+                    _ => return Err(Errors::SeedSchemaMismatch),
+                },
+            };
+            seeds.push(seed);
+        }
+        if values.next().is_some() {
+            return Err(Errors::SeedSchemaMismatch);
+        }
+        Ok(seeds)
+    }
+
+    /// Derives the PDA and canonical bump under `program_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::SeedSchemaMismatch` if the values don't match the schema.
+    pub fn find_address(
+        &self,
+        program_id: &Pubkey,
+        values: &[SeedValue],
+    ) -> Result<(Pubkey, u8), Errors> {
+        let seeds = self.seeds(values)?;
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        Ok(Pubkey::find_program_address(&seeds, program_id))
+    }
+
+    /// Derives a `PdaSigner` for the PDA under `program_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::SeedSchemaMismatch` if the values don't match the schema.
+    pub fn signer(&self, program_id: &Pubkey, values: &[SeedValue]) -> Result<PdaSigner, Errors> {
+        let seeds = self.seeds(values)?;
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        Ok(PdaSigner::derive(program_id, &seeds))
+    }
+
+    /// Builds a `PdaSigner` from a known bump, e.g. one stored in account state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The values don't match the schema (`Errors::SeedSchemaMismatch`)
+    /// - The seeds and bump don't form a valid PDA (`Errors::InvalidSeeds`)
+    pub fn signer_with_bump(
+        &self,
+        program_id: &Pubkey,
+        values: &[SeedValue],
+        bump: u8,
+    ) -> Result<PdaSigner, Errors> {
+        let seeds = self.seeds(values)?;
+        let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        PdaSigner::with_bump(program_id, &seeds, bump)
+    }
+}
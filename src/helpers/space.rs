@@ -0,0 +1,113 @@
+use crate::helpers::fixed::{BoundedVec, FixedBytes, FixedString};
+use solana_program::pubkey::Pubkey;
+
+/// Derives `AccountSpace` for structs and enums, see the trait.
+pub use solana_helpers_derive::AccountSpace;
+
+/// Serialized size of an account type, so account space is computed from the layout
+/// instead of by hand.
+///
+/// Derive it for account structs and enums with `#[derive(AccountSpace)]`, which sums
+/// the sizes of the fields; `#[account_space(header = Type)]` reserves the space of a
+/// discriminator or header type in front of them.
+///
+/// ```
+/// use solana_helpers::helpers::fixed::{BoundedVec, FixedString};
+/// use solana_helpers::helpers::space::AccountSpace;
+/// use solana_program::pubkey::Pubkey;
+///
+/// #[derive(AccountSpace)]
+/// #[account_space(header = [u8; 8])]
+/// pub struct Config {
+///     pub authority: Pubkey,
+///     pub name: FixedString<16>,
+///     pub admins: BoundedVec<Pubkey, 4>,
+/// }
+///
+/// assert_eq!(Config::max_space(), 8 + 32 + (4 + 16) + (4 + 4 * 32));
+/// ```
+pub trait AccountSpace {
+    /// Bytes in front of the fields, such as a discriminator or account header.
+    const PREFIX_SPACE: usize = 0;
+    /// Largest serialized size of the fields; exact for fixed-size types.
+    const MAX_SPACE: usize;
+
+    /// Serialized size of this value's fields.
+    fn space(&self) -> usize {
+        Self::MAX_SPACE
+    }
+
+    /// Account space needed for this value, prefix included.
+    fn account_space(&self) -> usize {
+        Self::PREFIX_SPACE + self.space()
+    }
+
+    /// Account space needed for any value of the type, prefix included.
+    #[must_use]
+    fn max_space() -> usize {
+        Self::PREFIX_SPACE + Self::MAX_SPACE
+    }
+}
+
+macro_rules! impl_primitive_space {
+    ($($ty:ty),*) => {
+        $(
+            impl AccountSpace for $ty {
+                const MAX_SPACE: usize = std::mem::size_of::<$ty>();
+            }
+        )*
+    };
+}
+
+impl_primitive_space!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool);
+
+impl AccountSpace for Pubkey {
+    const MAX_SPACE: usize = 32;
+}
+
+impl<T: AccountSpace, const N: usize> AccountSpace for [T; N] {
+    const MAX_SPACE: usize = T::MAX_SPACE * N;
+
+    fn space(&self) -> usize {
+        self.iter().map(AccountSpace::space).sum()
+    }
+}
+
+impl<T: AccountSpace> AccountSpace for Option<T> {
+    const MAX_SPACE: usize = 1 + T::MAX_SPACE;
+
+    fn space(&self) -> usize {
+        1 + self.as_ref().map_or(0, AccountSpace::space)
+    }
+}
+
+impl<const N: usize> AccountSpace for FixedBytes<N> {
+    const MAX_SPACE: usize = Self::LEN;
+}
+
+impl<const N: usize> AccountSpace for FixedString<N> {
+    const MAX_SPACE: usize = Self::LEN;
+}
+
+impl<T: AccountSpace, const N: usize> AccountSpace for BoundedVec<T, N> {
+    const MAX_SPACE: usize = 4 + T::MAX_SPACE * N;
+
+    fn space(&self) -> usize {
+        4 + self.iter().map(AccountSpace::space).sum::<usize>()
+    }
+}
+
+/// Returns the largest of `spaces`, for the derived `MAX_SPACE` of enums.
+#[doc(hidden)]
+#[must_use]
+pub const fn max_of(spaces: &[usize]) -> usize {
+    let mut max = 0;
+    let mut i = 0;
+    while i < spaces.len() {
+        if spaces[i] > max {
+            max = spaces[i];
+        }
+        i += 1;
+    }
+    max
+}
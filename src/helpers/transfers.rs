@@ -1,5 +1,8 @@
 use crate::errors::Errors;
+use crate::helpers::pda_signer::PdaSigner;
+use crate::helpers::validators::Validators;
 use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
 
 /// Provides utilities for transferring SOL and tokens.
 pub struct Transfers;
@@ -34,13 +37,16 @@ impl Transfers {
     ///
     /// # Errors
     ///
-    /// Returns an error if the program invocation fails
+    /// Returns an error if:
+    /// - `system_program` is not the System program
+    /// - The program invocation fails
     pub fn transfer_sol<'a>(
         from: AccountInfo<'a>,
         to: AccountInfo<'a>,
         system_program: AccountInfo<'a>,
         amount: u64,
     ) -> Result<(), Errors> {
+        Validators::assert_system_program(system_program.key)?;
         solana_program::program::invoke(
             &solana_program::system_instruction::transfer(from.key, to.key, amount),
             &[from, to, system_program],
@@ -48,61 +54,109 @@ impl Transfers {
         Ok(())
     }
 
-    /// Transfers SPL tokens between token accounts.
+    /// Transfers SOL out of a system-owned PDA using the system program transfer instruction,
+    /// signed by `signer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `system_program` is not the System program
+    /// - `from` is not the PDA `signer` signs for
+    /// - Program invocation with seeds fails
+    pub fn transfer_sol_signed<'a>(
+        from: AccountInfo<'a>,
+        to: AccountInfo<'a>,
+        system_program: AccountInfo<'a>,
+        amount: u64,
+        signer: &PdaSigner,
+    ) -> Result<(), Errors> {
+        Validators::assert_system_program(system_program.key)?;
+        signer.verify(&from)?;
+        PdaSigner::invoke(
+            &solana_program::system_instruction::transfer(from.key, to.key, amount),
+            &[from, to, system_program],
+            &[signer],
+        )
+    }
+
+    /// Transfers SPL tokens between token accounts with `transfer_checked`, which
+    /// Token-2022 requires for mints with transfer fee or transfer hook extensions.
+    ///
+    /// Pass the signing members in `multisig_signers` when `owner` is an SPL multisig,
+    /// or an empty slice for a single-key owner.
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - `token_program` is neither SPL Token nor Token-2022
+    /// - The multisig signers don't satisfy the multisig threshold
     /// - Token instruction creation fails
-    /// - Program invocation fails
+    /// - Program invocation fails, e.g. because `mint` or `decimals` don't match `from`
+    #[allow(clippy::too_many_arguments)]
     pub fn transfer_token<'a>(
         from: AccountInfo<'a>,
+        mint: AccountInfo<'a>,
         to: AccountInfo<'a>,
         token_program: AccountInfo<'a>,
         owner: AccountInfo<'a>,
         amount: u64,
+        decimals: u8,
+        multisig_signers: &[AccountInfo<'a>],
     ) -> Result<(), Errors> {
-        solana_program::program::invoke(
-            &spl_token::instruction::transfer(
-                token_program.key,
-                from.key,
-                to.key,
-                owner.key,
-                &[],
-                amount,
-            )?,
-            &[from, to, token_program, owner],
-        )?;
-        Ok(())
+        Self::transfer_token_from_pda(
+            from,
+            mint,
+            to,
+            token_program,
+            owner,
+            amount,
+            decimals,
+            multisig_signers,
+            &[],
+        )
     }
 
-    /// Transfers SPL tokens from a PDA-owned token account.
+    /// Transfers SPL tokens from a PDA-owned token account with `transfer_checked`.
+    ///
+    /// Pass the signing members in `multisig_signers` when `owner` is an SPL multisig,
+    /// or an empty slice for a single-key owner. Pass in `signers` every PDA of the calling
+    /// program that has to sign: `owner` itself, or the multisig members.
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - `token_program` is neither SPL Token nor Token-2022
+    /// - The multisig signers don't satisfy the multisig threshold
     /// - Token instruction creation fails
-    /// - Program invocation with seeds fails
+    /// - Program invocation with seeds fails, e.g. because `mint` or `decimals` don't
+    ///   match `from`
+    #[allow(clippy::too_many_arguments)]
     pub fn transfer_token_from_pda<'a>(
         from: AccountInfo<'a>,
+        mint: AccountInfo<'a>,
         to: AccountInfo<'a>,
         token_program: AccountInfo<'a>,
         owner: AccountInfo<'a>,
         amount: u64,
-        seeds: &[&[&[u8]]],
+        decimals: u8,
+        multisig_signers: &[AccountInfo<'a>],
+        signers: &[&PdaSigner],
     ) -> Result<(), Errors> {
-        solana_program::program::invoke_signed(
-            &spl_token::instruction::transfer(
-                token_program.key,
-                from.key,
-                to.key,
-                owner.key,
-                &[],
-                amount,
-            )?,
-            &[from, to, token_program, owner],
-            seeds,
+        Validators::assert_token_program(token_program.key)?;
+        Validators::assert_multisig(&owner, multisig_signers)?;
+        let signer_keys: Vec<&Pubkey> = multisig_signers.iter().map(|signer| signer.key).collect();
+        let ix = spl_token_2022::instruction::transfer_checked(
+            token_program.key,
+            from.key,
+            mint.key,
+            to.key,
+            owner.key,
+            &signer_keys,
+            amount,
+            decimals,
         )?;
-        Ok(())
+        let mut account_infos = vec![from, mint, to, token_program, owner];
+        account_infos.extend_from_slice(multisig_signers);
+        PdaSigner::invoke(&ix, &account_infos, signers)
     }
 }
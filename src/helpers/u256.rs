@@ -0,0 +1,356 @@
+use crate::errors::Errors;
+use crate::helpers::space::AccountSpace;
+use borsh::{BorshDeserialize, BorshSerialize};
+#[cfg(feature = "client")]
+use num_bigint::{BigInt, Sign};
+use std::cmp::Ordering;
+use std::fmt;
+use std::io::{Read, Write};
+
+/// A 256-bit unsigned integer on the stack, for number handling inside programs where
+/// `BigInt` heap-allocates.
+///
+/// Arithmetic is checked, like the `checked_*` methods of the primitive integers.
+/// Borsh and account layouts store it as 32 little-endian bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct U256([u64; 4]);
+
+impl U256 {
+    /// Size in bytes.
+    pub const LEN: usize = 32;
+    /// Zero.
+    pub const ZERO: Self = Self([0; 4]);
+    /// One.
+    pub const ONE: Self = Self([1, 0, 0, 0]);
+    /// The largest value, `2^256 - 1`.
+    pub const MAX: Self = Self([u64::MAX; 4]);
+    /// Modulus of the BN254 base field.
+    pub const BN254_BASE_MODULUS: Self = Self([
+        0x3c20_8c16_d87c_fd47,
+        0x9781_6a91_6871_ca8d,
+        0xb850_45b6_8181_585d,
+        0x3064_4e72_e131_a029,
+    ]);
+    /// Modulus of the BN254 scalar field, which public inputs and Poseidon inputs are
+    /// below.
+    pub const BN254_SCALAR_MODULUS: Self = Self([
+        0x43e1_f593_f000_0001,
+        0x2833_e848_79b9_7091,
+        0xb850_45b6_8181_585d,
+        0x3064_4e72_e131_a029,
+    ]);
+
+    /// Creates a value from a `u64`.
+    #[must_use]
+    pub const fn from_u64(value: u64) -> Self {
+        Self([value, 0, 0, 0])
+    }
+
+    /// Creates a value from a `u128`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn from_u128(value: u128) -> Self {
+        Self([value as u64, (value >> 64) as u64, 0, 0])
+    }
+
+    /// Reads 32 big-endian bytes.
+    #[must_use]
+    pub const fn from_be_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut i = 0;
+        while i < 32 {
+            limbs[3 - i / 8] |= (bytes[i] as u64) << (8 * (7 - i % 8));
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// Reads 32 little-endian bytes.
+    #[must_use]
+    pub const fn from_le_bytes(bytes: &[u8; 32]) -> Self {
+        let mut limbs = [0u64; 4];
+        let mut i = 0;
+        while i < 32 {
+            limbs[i / 8] |= (bytes[i] as u64) << (8 * (i % 8));
+            i += 1;
+        }
+        Self(limbs)
+    }
+
+    /// Reads up to 32 big-endian bytes, as a shorter number is left-padded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NumericalOverflow` if `bytes` is longer than 32 bytes.
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, Errors> {
+        let start = Self::LEN
+            .checked_sub(bytes.len())
+            .ok_or(Errors::NumericalOverflow)?;
+        let mut padded = [0u8; 32];
+        padded[start..].copy_from_slice(bytes);
+        Ok(Self::from_be_bytes(&padded))
+    }
+
+    /// Reads up to 32 little-endian bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NumericalOverflow` if `bytes` is longer than 32 bytes.
+    pub fn from_le_slice(bytes: &[u8]) -> Result<Self, Errors> {
+        if bytes.len() > Self::LEN {
+            return Err(Errors::NumericalOverflow);
+        }
+        let mut padded = [0u8; 32];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        Ok(Self::from_le_bytes(&padded))
+    }
+
+    /// Returns the value as 32 big-endian bytes.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            bytes[i] = (self.0[3 - i / 8] >> (8 * (7 - i % 8))) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Returns the value as 32 little-endian bytes.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_le_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            bytes[i] = (self.0[i / 8] >> (8 * (i % 8))) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Returns the value as a `u64`, or `None` if it doesn't fit.
+    #[must_use]
+    pub const fn to_u64(&self) -> Option<u64> {
+        match self.0 {
+            [value, 0, 0, 0] => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the value is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        matches!(self.0, [0, 0, 0, 0])
+    }
+
+    /// Returns the value if it is below `modulus`, i.e. a canonical field element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FieldElementOutOfRange` if the value is not below `modulus`.
+    pub fn ensure_below(self, modulus: &Self) -> Result<Self, Errors> {
+        if self >= *modulus {
+            return Err(Errors::FieldElementOutOfRange);
+        }
+        Ok(self)
+    }
+
+    /// Adds, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut carry = false;
+        let mut i = 0;
+        while i < 4 {
+            let (sum, over) = self.0[i].overflowing_add(rhs.0[i]);
+            let (sum, over_carry) = sum.overflowing_add(carry as u64);
+            limbs[i] = sum;
+            carry = over || over_carry;
+            i += 1;
+        }
+        if carry {
+            None
+        } else {
+            Some(Self(limbs))
+        }
+    }
+
+    /// Subtracts, returning `None` on underflow.
+    #[must_use]
+    pub const fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut borrow = false;
+        let mut i = 0;
+        while i < 4 {
+            let (diff, under) = self.0[i].overflowing_sub(rhs.0[i]);
+            let (diff, under_borrow) = diff.overflowing_sub(borrow as u64);
+            limbs[i] = diff;
+            borrow = under || under_borrow;
+            i += 1;
+        }
+        if borrow {
+            None
+        } else {
+            Some(Self(limbs))
+        }
+    }
+
+    /// Multiplies, returning `None` on overflow.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let mut limbs = [0u64; 4];
+        let mut i = 0;
+        while i < 4 {
+            let mut carry = 0u128;
+            let mut j = 0;
+            while j < 4 {
+                let product = self.0[i] as u128 * rhs.0[j] as u128;
+                if i + j < 4 {
+                    let sum = limbs[i + j] as u128 + product + carry;
+                    limbs[i + j] = sum as u64;
+                    carry = sum >> 64;
+                } else if product != 0 {
+                    return None;
+                }
+                j += 1;
+            }
+            if carry != 0 {
+                return None;
+            }
+            i += 1;
+        }
+        Some(Self(limbs))
+    }
+
+    /// Divides, returning `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+
+    /// Returns the remainder of the division, or `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+
+    /// Divides, returning the quotient and remainder, or `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        for bit in (0..256).rev() {
+            remainder = remainder.shl1();
+            remainder.0[0] |= (self.0[bit / 64] >> (bit % 64)) & 1;
+            if remainder >= *rhs {
+                remainder = remainder.checked_sub(rhs)?;
+                quotient.0[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+        Some((quotient, remainder))
+    }
+
+    /// Shifts left by one bit, dropping the top bit.
+    const fn shl1(self) -> Self {
+        Self([
+            self.0[0] << 1,
+            (self.0[1] << 1) | (self.0[0] >> 63),
+            (self.0[2] << 1) | (self.0[1] >> 63),
+            (self.0[3] << 1) | (self.0[2] >> 63),
+        ])
+    }
+}
+
+impl Ord for U256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl PartialOrd for U256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u64> for U256 {
+    fn from(value: u64) -> Self {
+        Self::from_u64(value)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+}
+
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const TEN: U256 = U256::from_u64(10);
+        let mut digits = Vec::new();
+        let mut value = *self;
+        loop {
+            let Some((quotient, digit)) = value.checked_div_rem(&TEN) else {
+                return Err(fmt::Error);
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            digits.push(b'0' + digit.0[0] as u8);
+            value = quotient;
+            if value.is_zero() {
+                break;
+            }
+        }
+        digits.reverse();
+        f.pad_integral(
+            true,
+            "",
+            std::str::from_utf8(&digits).map_err(|_| fmt::Error)?,
+        )
+    }
+}
+
+impl BorshSerialize for U256 {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+}
+
+impl BorshDeserialize for U256 {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut bytes = [0u8; 32];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self::from_le_bytes(&bytes))
+    }
+}
+
+impl AccountSpace for U256 {
+    const MAX_SPACE: usize = Self::LEN;
+}
+
+#[cfg(feature = "client")]
+impl From<U256> for BigInt {
+    fn from(value: U256) -> Self {
+        Self::from_bytes_le(Sign::Plus, &value.to_le_bytes())
+    }
+}
+
+#[cfg(feature = "client")]
+impl TryFrom<&BigInt> for U256 {
+    type Error = Errors;
+
+    fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+        let (sign, bytes) = value.to_bytes_le();
+        if sign == Sign::Minus {
+            return Err(Errors::NumericalOverflow);
+        }
+        Self::from_le_slice(&bytes)
+    }
+}
@@ -1,4 +1,6 @@
 use crate::errors::Errors;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use spl_associated_token_account::get_associated_token_address;
 
@@ -25,4 +27,85 @@ impl Validators {
         }
         Ok(())
     }
+
+    /// Validates that a program id is the System program.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidProgramId` if `program_id` is not the System program.
+    pub fn assert_system_program(program_id: &Pubkey) -> Result<(), Errors> {
+        if *program_id != solana_system_interface::program::ID {
+            return Err(Errors::InvalidProgramId);
+        }
+        Ok(())
+    }
+
+    /// Validates that a program id is the SPL Token or Token-2022 program.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidProgramId` if `program_id` is neither token program.
+    pub fn assert_token_program(program_id: &Pubkey) -> Result<(), Errors> {
+        if *program_id != spl_token::ID && *program_id != spl_token_2022::ID {
+            return Err(Errors::InvalidProgramId);
+        }
+        Ok(())
+    }
+
+    /// Validates that a program id is the Associated Token Account program.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidProgramId` if `program_id` is not the ATA program.
+    pub fn assert_associated_token_program(program_id: &Pubkey) -> Result<(), Errors> {
+        if *program_id != spl_associated_token_account::ID {
+            return Err(Errors::InvalidProgramId);
+        }
+        Ok(())
+    }
+
+    /// Validates the multisig signers passed alongside a token `authority`.
+    ///
+    /// With no signers the authority is treated as a single-key authority and nothing is
+    /// checked. Otherwise the authority must be an initialized SPL multisig account, every
+    /// signer must be one of its members, and at least `m` distinct members must be
+    /// present. Signatures themselves are enforced by the token program.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The authority is not a multisig owned by a token program
+    ///   (`Errors::InvalidAccountOwner`, `Errors::InvalidAccountData`)
+    /// - A signer is not a member of the multisig (`Errors::InvalidMultisigSigner`)
+    /// - Fewer than `m` distinct members are present (`Errors::NotEnoughSigners`)
+    pub fn assert_multisig(
+        authority: &AccountInfo,
+        multisig_signers: &[AccountInfo],
+    ) -> Result<(), Errors> {
+        if multisig_signers.is_empty() {
+            return Ok(());
+        }
+        if Self::assert_token_program(authority.owner).is_err() {
+            return Err(Errors::InvalidAccountOwner);
+        }
+        let data = authority.try_borrow_data()?;
+        if data.len() != spl_token::state::Multisig::LEN {
+            return Err(Errors::InvalidAccountData);
+        }
+        let multisig =
+            spl_token::state::Multisig::unpack(&data).map_err(|_| Errors::InvalidAccountData)?;
+        let members = &multisig.signers[..usize::from(multisig.n)];
+        let mut matched = [false; spl_token::instruction::MAX_SIGNERS];
+        for signer in multisig_signers {
+            let position = members
+                .iter()
+                .position(|member| member == signer.key)
+                .ok_or(Errors::InvalidMultisigSigner)?;
+            matched[position] = true;
+        }
+        if matched.iter().filter(|matched| **matched).count() < usize::from(multisig.m) {
+            return Err(Errors::NotEnoughSigners);
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,120 @@
+use crate::errors::Errors;
+use crate::helpers::pda_signer::PdaSigner;
+use crate::helpers::transfers::Transfers;
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+/// Provides utilities for SOL vaults: system-owned PDAs with no data that only hold lamports.
+///
+/// Keeping the vault owned by the System program means it can never be reassigned or
+/// hold data, and lamports can only leave it through a transfer signed with its seeds.
+pub struct SolVault;
+
+impl SolVault {
+    /// Derives the vault address and bump for `seeds` under `program_id`.
+    #[must_use]
+    pub fn derive(program_id: &Pubkey, seeds: &[&[u8]]) -> (Pubkey, u8) {
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Deposits SOL from `payer` into the vault.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The vault key doesn't match the PDA derived from `seeds`
+    /// - The vault is not an empty system-owned account
+    /// - The vault would hold less than the rent-exempt minimum
+    /// - The SOL transfer fails
+    pub fn fund<'a>(
+        payer: AccountInfo<'a>,
+        vault: AccountInfo<'a>,
+        system_program: AccountInfo<'a>,
+        amount: u64,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+    ) -> Result<(), Errors> {
+        PdaSigner::derive(program_id, seeds).verify(&vault)?;
+        Self::assert_vault(&vault)?;
+        let post_vault = vault
+            .lamports()
+            .checked_add(amount)
+            .ok_or(Errors::NumericalOverflow)?;
+        if post_vault < Rent::get()?.minimum_balance(0) {
+            return Err(Errors::InsufficientFundsForRent);
+        }
+        Transfers::transfer_sol(payer, vault, system_program, amount)
+    }
+
+    /// Withdraws SOL from the vault, keeping it rent-exempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The vault key doesn't match the PDA derived from `seeds`
+    /// - The vault is not an empty system-owned account
+    /// - The vault would be left below the rent-exempt minimum
+    /// - The SOL transfer fails
+    pub fn withdraw<'a>(
+        vault: AccountInfo<'a>,
+        to: AccountInfo<'a>,
+        system_program: AccountInfo<'a>,
+        amount: u64,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+    ) -> Result<(), Errors> {
+        let post_vault = vault
+            .lamports()
+            .checked_sub(amount)
+            .ok_or(Errors::NumericalOverflow)?;
+        if post_vault < Rent::get()?.minimum_balance(0) {
+            return Err(Errors::InsufficientFundsForRent);
+        }
+        Self::signed_transfer(vault, to, system_program, amount, program_id, seeds)
+    }
+
+    /// Closes the vault by transferring its entire balance to `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The vault key doesn't match the PDA derived from `seeds`
+    /// - The vault is not an empty system-owned account
+    /// - The SOL transfer fails
+    pub fn close<'a>(
+        vault: AccountInfo<'a>,
+        to: AccountInfo<'a>,
+        system_program: AccountInfo<'a>,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+    ) -> Result<(), Errors> {
+        let amount = vault.lamports();
+        Self::signed_transfer(vault, to, system_program, amount, program_id, seeds)
+    }
+
+    fn signed_transfer<'a>(
+        vault: AccountInfo<'a>,
+        to: AccountInfo<'a>,
+        system_program: AccountInfo<'a>,
+        amount: u64,
+        program_id: &Pubkey,
+        seeds: &[&[u8]],
+    ) -> Result<(), Errors> {
+        let signer = PdaSigner::derive(program_id, seeds);
+        signer.verify(&vault)?;
+        Self::assert_vault(&vault)?;
+        Transfers::transfer_sol_signed(vault, to, system_program, amount, &signer)
+    }
+
+    fn assert_vault(vault: &AccountInfo) -> Result<(), Errors> {
+        if *vault.owner != solana_system_interface::program::ID {
+            return Err(Errors::InvalidAccountOwner);
+        }
+        if !vault.try_data_is_empty()? {
+            return Err(Errors::InvalidAccountData);
+        }
+        Ok(())
+    }
+}
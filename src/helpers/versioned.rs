@@ -0,0 +1,302 @@
+use crate::errors::Errors;
+use crate::helpers::generic::Generic;
+use crate::helpers::space::AccountSpace;
+use crate::helpers::transfers::Transfers;
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+/// Header at the start of a versioned account: the `Generic::derive_discriminator`
+/// prefix, the schema version and, optionally, who paid the account's rent.
+///
+/// Layout: 8-byte discriminator, little-endian `u32` version, a flags byte, 3 reserved
+/// zero bytes and the 32-byte rent payer, so the account body that follows stays 8-byte
+/// aligned. The rent payer bytes are only meaningful when the flags byte marks them as
+/// recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountHeader {
+    /// Discriminator of the account type.
+    pub discriminator: [u8; 8],
+    /// Schema version of the body.
+    pub version: u32,
+    /// The account that paid the rent, if recorded, see `RentPayer`.
+    pub rent_payer: Option<Pubkey>,
+}
+
+impl AccountHeader {
+    /// Size of the header in bytes.
+    pub const LEN: usize = 48;
+
+    /// Flag marking the rent payer field as recorded.
+    const RENT_PAYER_FLAG: u8 = 1;
+
+    /// Reads the header from the start of `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidAccountData` if `data` is shorter than the header or the
+    /// flags byte has unknown bits set.
+    pub fn read(data: &[u8]) -> Result<Self, Errors> {
+        let header = data.get(..Self::LEN).ok_or(Errors::InvalidAccountData)?;
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&header[..8]);
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&header[8..12]);
+        let rent_payer = match header[12] {
+            0 => None,
+            Self::RENT_PAYER_FLAG => {
+                Some(Pubkey::try_from(&header[16..48]).map_err(|_| Errors::InvalidAccountData)?)
+            }
+            _ => return Err(Errors::InvalidAccountData),
+        };
+        Ok(Self {
+            discriminator,
+            version: u32::from_le_bytes(version),
+            rent_payer,
+        })
+    }
+
+    /// Writes the header to the start of `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidAccountData` if `data` is shorter than the header.
+    pub fn write(&self, data: &mut [u8]) -> Result<(), Errors> {
+        let header = data
+            .get_mut(..Self::LEN)
+            .ok_or(Errors::InvalidAccountData)?;
+        header[..8].copy_from_slice(&self.discriminator);
+        header[8..12].copy_from_slice(&self.version.to_le_bytes());
+        header[12..].fill(0);
+        if let Some(rent_payer) = self.rent_payer {
+            header[12] = Self::RENT_PAYER_FLAG;
+            header[16..48].copy_from_slice(rent_payer.as_ref());
+        }
+        Ok(())
+    }
+}
+
+impl AccountSpace for AccountHeader {
+    const MAX_SPACE: usize = Self::LEN;
+}
+
+/// Rewrites an account body from one schema version to the next.
+pub type Upgrade = fn(&[u8]) -> Result<Vec<u8>, Errors>;
+
+/// The schema history of a versioned account type.
+///
+/// `upgrades[n]` turns a version `n` body into a version `n + 1` body, so the current
+/// version is the number of upgrades. Declare it once per account type:
+///
+/// ```
+/// use solana_helpers::errors::Errors;
+/// use solana_helpers::helpers::versioned::AccountSchema;
+///
+/// fn add_counter(body: &[u8]) -> Result<Vec<u8>, Errors> {
+///     Ok([body, &0u64.to_le_bytes()].concat())
+/// }
+///
+/// const CONFIG: AccountSchema = AccountSchema::new("Config", &[add_counter]);
+/// assert_eq!(CONFIG.current_version(), 1);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AccountSchema {
+    name: &'static str,
+    upgrades: &'static [Upgrade],
+}
+
+impl AccountSchema {
+    /// Creates a schema for the account type `name` with its upgrades in order.
+    #[must_use]
+    pub const fn new(name: &'static str, upgrades: &'static [Upgrade]) -> Self {
+        Self { name, upgrades }
+    }
+
+    /// Returns the discriminator derived from the account type name.
+    #[must_use]
+    pub fn discriminator(&self) -> [u8; 8] {
+        Generic::derive_discriminator(self.name)
+    }
+
+    /// Returns the current schema version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `u32::MAX` upgrades.
+    #[must_use]
+    pub const fn current_version(&self) -> u32 {
+        assert!(
+            self.upgrades.len() <= u32::MAX as usize,
+            "too many upgrades"
+        );
+        #[allow(clippy::cast_possible_truncation)]
+        let version = self.upgrades.len() as u32;
+        version
+    }
+
+    /// Returns the header of an account at the current version.
+    #[must_use]
+    pub fn header(&self) -> AccountHeader {
+        AccountHeader {
+            discriminator: self.discriminator(),
+            version: self.current_version(),
+            rent_payer: None,
+        }
+    }
+
+    /// Returns the space an account with a body of `body_len` bytes needs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::NumericalOverflow` if the size overflows.
+    pub fn space(body_len: usize) -> Result<usize, Errors> {
+        body_len
+            .checked_add(AccountHeader::LEN)
+            .ok_or(Errors::NumericalOverflow)
+    }
+
+    /// Writes the current header and `body` into a freshly created account, keeping the
+    /// rent payer `Generic::create_pda_account_with_rent_payer` may have recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account data isn't exactly `AccountHeader::LEN + body.len()` bytes
+    ///   (`Errors::InvalidAccountData`)
+    /// - Unable to borrow account data
+    pub fn initialize(&self, account: &AccountInfo, body: &[u8]) -> Result<(), Errors> {
+        let mut data = account.try_borrow_mut_data()?;
+        if data.len() != Self::space(body.len())? {
+            return Err(Errors::InvalidAccountData);
+        }
+        AccountHeader {
+            rent_payer: AccountHeader::read(&data)?.rent_payer,
+            ..self.header()
+        }
+        .write(&mut data)?;
+        data[AccountHeader::LEN..].copy_from_slice(body);
+        Ok(())
+    }
+
+    /// Returns the version of the account, checking its discriminator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The header can't be read (`Errors::InvalidAccountData`)
+    /// - The discriminator belongs to another type (`Errors::DiscriminatorMismatch`)
+    /// - The version is newer than this program knows (`Errors::UnsupportedAccountVersion`)
+    pub fn version(&self, data: &[u8]) -> Result<u32, Errors> {
+        let header = AccountHeader::read(data)?;
+        if header.discriminator != self.discriminator() {
+            return Err(Errors::DiscriminatorMismatch);
+        }
+        if header.version > self.current_version() {
+            return Err(Errors::UnsupportedAccountVersion);
+        }
+        Ok(header.version)
+    }
+
+    /// Returns the account body, which must be at the current version.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::UnsupportedAccountVersion` if the account still needs `migrate`,
+    /// or any error of `version`.
+    pub fn body<'d>(&self, data: &'d [u8]) -> Result<&'d [u8], Errors> {
+        if self.version(data)? != self.current_version() {
+            return Err(Errors::UnsupportedAccountVersion);
+        }
+        Ok(&data[AccountHeader::LEN..])
+    }
+
+    /// Upgrades the account to the current version in place, if needed.
+    ///
+    /// Runs the pending upgrades on the body, reallocates the account to the new size and
+    /// keeps it rent-exempt: `payer` tops up the lamports a larger account needs and gets
+    /// back the rent a smaller one frees; lamports above the rent are left untouched.
+    /// Freed rent only goes to the rent payer recorded in the header or, when none is
+    /// recorded, to a `payer` that signed the transaction.
+    /// Call it on first touch, before `body`.
+    ///
+    /// Returns `true` if the account was migrated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account isn't owned by `program_id` (`Errors::InvalidAccountOwner`)
+    /// - The account is not a valid account of this type (see `version`)
+    /// - An upgrade fails
+    /// - Rent calculation fails
+    /// - Freed rent would go to a `payer` other than the recorded rent payer
+    ///   (`Errors::RentPayerMismatch`), or to an unrecorded `payer` that didn't sign
+    ///   (`Errors::MissingRequiredSignature`)
+    /// - The rent top-up transfer or the reallocation fails
+    pub fn migrate<'a>(
+        &self,
+        account: &AccountInfo<'a>,
+        program_id: &Pubkey,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> Result<bool, Errors> {
+        if account.owner != program_id {
+            return Err(Errors::InvalidAccountOwner);
+        }
+        let (version, rent_payer, mut body) = {
+            let data = account.try_borrow_data()?;
+            (
+                self.version(&data)?,
+                AccountHeader::read(&data)?.rent_payer,
+                data[AccountHeader::LEN..].to_vec(),
+            )
+        };
+        let Some(pending) = self.upgrades.get(version as usize..) else {
+            return Err(Errors::UnsupportedAccountVersion);
+        };
+        if pending.is_empty() {
+            return Ok(false);
+        }
+        for upgrade in pending {
+            body = upgrade(&body)?;
+        }
+
+        let space = Self::space(body.len())?;
+        let rent = Rent::get()?;
+        let rent_exempt = rent.minimum_balance(space);
+        let lamports = account.lamports();
+        if lamports < rent_exempt {
+            Transfers::transfer_sol(
+                payer.clone(),
+                account.clone(),
+                system_program.clone(),
+                rent_exempt - lamports,
+            )?;
+        } else {
+            let freed = rent
+                .minimum_balance(account.data_len())
+                .saturating_sub(rent_exempt)
+                .min(lamports - rent_exempt);
+            if freed > 0 {
+                match rent_payer {
+                    Some(rent_payer) if rent_payer != *payer.key => {
+                        return Err(Errors::RentPayerMismatch);
+                    }
+                    None if !payer.is_signer => return Err(Errors::MissingRequiredSignature),
+                    _ => {}
+                }
+                Transfers::transfer_sol_from_pda(&mut account.clone(), &mut payer.clone(), freed)?;
+            }
+        }
+
+        account.resize(space)?;
+        let mut data = account.try_borrow_mut_data()?;
+        AccountHeader {
+            rent_payer,
+            ..self.header()
+        }
+        .write(&mut data)?;
+        data[AccountHeader::LEN..].copy_from_slice(&body);
+        Ok(true)
+    }
+}
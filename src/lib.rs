@@ -9,7 +9,12 @@
 // #![doc(test(no_crate_inject, attr(deny(warnings, rust_2018_idioms)), allow(dead_code, unused_variables)))]
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
+// Lets the derive macros name this crate as `solana_helpers` from inside it too.
+extern crate self as solana_helpers;
+
 /// Error types used throughout the library.
 pub mod errors;
 /// Helper modules containing utility functions for Solana programs.
 pub mod helpers;
+/// Zero-knowledge proof verification helpers.
+pub mod zk;
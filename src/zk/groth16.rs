@@ -0,0 +1,158 @@
+use crate::errors::Errors;
+use crate::helpers::u256::U256;
+use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing};
+
+/// `U256::BN254_BASE_MODULUS` as big-endian bytes; point coordinates are below it.
+pub const BASE_FIELD_MODULUS: [u8; 32] = U256::BN254_BASE_MODULUS.to_be_bytes();
+/// `U256::BN254_SCALAR_MODULUS` as big-endian bytes; public inputs are below it.
+pub const SCALAR_FIELD_MODULUS: [u8; 32] = U256::BN254_SCALAR_MODULUS.to_be_bytes();
+
+/// Size of an uncompressed G1 point: big-endian `x || y`.
+pub const G1_LEN: usize = 64;
+/// Size of an uncompressed G2 point: big-endian `x_c1 || x_c0 || y_c1 || y_c0`.
+pub const G2_LEN: usize = 128;
+
+/// A Groth16 verifying key, with points in the big-endian uncompressed layout of the
+/// `alt_bn128` syscalls (EIP-197).
+///
+/// Borrowing `ic` lets programs embed keys as constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyingKey<'a> {
+    /// `alpha` in G1.
+    pub alpha_g1: [u8; G1_LEN],
+    /// `beta` in G2.
+    pub beta_g2: [u8; G2_LEN],
+    /// `gamma` in G2.
+    pub gamma_g2: [u8; G2_LEN],
+    /// `delta` in G2.
+    pub delta_g2: [u8; G2_LEN],
+    /// The constant point followed by one point per public input.
+    pub ic: &'a [[u8; G1_LEN]],
+}
+
+/// A Groth16 proof, with points in the layout of `VerifyingKey`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Proof {
+    /// `A` in G1.
+    pub a: [u8; G1_LEN],
+    /// `B` in G2.
+    pub b: [u8; G2_LEN],
+    /// `C` in G1.
+    pub c: [u8; G1_LEN],
+}
+
+/// Provides Groth16 proof verification over BN254.
+///
+/// Public inputs are big-endian scalars. Verification checks
+/// `e(-A, B) * e(vk_x, gamma) * e(C, delta) * e(alpha, beta) == 1`, where `vk_x` is
+/// `ic[0] + sum(input[i] * ic[i + 1])`, with a single pairing syscall.
+pub struct Groth16;
+
+impl Groth16 {
+    /// Verifies `proof` against `vk` and `public_inputs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The number of inputs doesn't match `vk.ic` (`Errors::PublicInputCountMismatch`)
+    /// - An input is not below `SCALAR_FIELD_MODULUS` (`Errors::PublicInputOutOfField`)
+    /// - A point is malformed or not on the curve (`Errors::InvalidCurvePoint`)
+    /// - The pairing check fails (`Errors::ProofVerificationFailed`)
+    pub fn verify(
+        vk: &VerifyingKey,
+        proof: &Proof,
+        public_inputs: &[[u8; 32]],
+    ) -> Result<(), Errors> {
+        let a = Self::negate_g1(&proof.a)?;
+        Self::verify_negated(vk, &Proof { a, ..*proof }, public_inputs)
+    }
+
+    /// Verifies a proof whose `A` was already negated off-chain, saving the negation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `verify`.
+    pub fn verify_negated(
+        vk: &VerifyingKey,
+        proof: &Proof,
+        public_inputs: &[[u8; 32]],
+    ) -> Result<(), Errors> {
+        let vk_x = Self::prepare_inputs(vk, public_inputs)?;
+        let pairs = [
+            proof.a.as_slice(),
+            &proof.b,
+            &vk_x,
+            &vk.gamma_g2,
+            &proof.c,
+            &vk.delta_g2,
+            &vk.alpha_g1,
+            &vk.beta_g2,
+        ]
+        .concat();
+        let result = alt_bn128_pairing(&pairs).map_err(|_| Errors::InvalidCurvePoint)?;
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        if result != one {
+            return Err(Errors::ProofVerificationFailed);
+        }
+        Ok(())
+    }
+
+    /// Computes `ic[0] + sum(input[i] * ic[i + 1])`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::PublicInputCountMismatch`, `Errors::PublicInputOutOfField` or
+    /// `Errors::InvalidCurvePoint`, see `verify`.
+    pub fn prepare_inputs(
+        vk: &VerifyingKey,
+        public_inputs: &[[u8; 32]],
+    ) -> Result<[u8; G1_LEN], Errors> {
+        let Some((first, points)) = vk.ic.split_first() else {
+            return Err(Errors::PublicInputCountMismatch);
+        };
+        if points.len() != public_inputs.len() {
+            return Err(Errors::PublicInputCountMismatch);
+        }
+        let mut vk_x = *first;
+        for (point, input) in points.iter().zip(public_inputs) {
+            if !Self::is_scalar(input) {
+                return Err(Errors::PublicInputOutOfField);
+            }
+            let product = alt_bn128_multiplication(&[point.as_slice(), input].concat())
+                .map_err(|_| Errors::InvalidCurvePoint)?;
+            let sum = alt_bn128_addition(&[vk_x.as_slice(), &product].concat())
+                .map_err(|_| Errors::InvalidCurvePoint)?;
+            vk_x = sum.try_into().map_err(|_| Errors::InvalidCurvePoint)?;
+        }
+        Ok(vk_x)
+    }
+
+    /// Negates a G1 point, mapping `(x, y)` to `(x, p - y)` and the identity to itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidCurvePoint` if `y` is not below `BASE_FIELD_MODULUS`.
+    pub fn negate_g1(point: &[u8; G1_LEN]) -> Result<[u8; G1_LEN], Errors> {
+        let mut negated = *point;
+        let y = U256::from_be_slice(&point[32..])?;
+        if y >= U256::BN254_BASE_MODULUS {
+            return Err(Errors::InvalidCurvePoint);
+        }
+        if !y.is_zero() {
+            let negated_y = U256::BN254_BASE_MODULUS
+                .checked_sub(&y)
+                .ok_or(Errors::InvalidCurvePoint)?;
+            negated[32..].copy_from_slice(&negated_y.to_be_bytes());
+        }
+        Ok(negated)
+    }
+
+    /// Returns whether a big-endian scalar is below `SCALAR_FIELD_MODULUS`.
+    #[must_use]
+    pub fn is_scalar(input: &[u8; 32]) -> bool {
+        U256::from_be_bytes(input)
+            .ensure_below(&U256::BN254_SCALAR_MODULUS)
+            .is_ok()
+    }
+}
@@ -0,0 +1,7 @@
+/// Groth16 proof verification over BN254 using the `alt_bn128` syscalls.
+pub mod groth16;
+/// Poseidon hashing matching circom, via the syscall on-chain.
+pub mod poseidon;
+/// Conversion of snarkjs / circom Groth16 artifacts into syscall byte layouts.
+#[cfg(feature = "client")]
+pub mod snarkjs;
@@ -0,0 +1,115 @@
+use crate::errors::Errors;
+use crate::helpers::conversions::Conversions;
+use crate::helpers::u256::U256;
+#[cfg(feature = "client")]
+use num_bigint::{BigInt, Sign};
+use solana_poseidon::{Parameters, PoseidonSyscallError};
+
+/// Byte order of field elements, inputs and hash alike.
+///
+/// A little-endian element is the big-endian one passed through
+/// `Conversions::change_endianness`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    /// Most significant byte first, as circom and snarkjs print them.
+    BigEndian,
+    /// Least significant byte first, as arkworks serializes them.
+    LittleEndian,
+}
+
+impl Endianness {
+    /// Returns `bytes`, in this byte order, as big-endian.
+    fn to_big_endian(self, bytes: &[u8; 32]) -> [u8; 32] {
+        match self {
+            Self::BigEndian => *bytes,
+            Self::LittleEndian => Conversions::vec_to_array(&Conversions::change_endianness(bytes)),
+        }
+    }
+}
+
+impl From<Endianness> for solana_poseidon::Endianness {
+    fn from(endianness: Endianness) -> Self {
+        match endianness {
+            Endianness::BigEndian => Self::BigEndian,
+            Endianness::LittleEndian => Self::LittleEndian,
+        }
+    }
+}
+
+/// Provides Poseidon hashing over the BN254 scalar field with circom's parameters
+/// (x^5 S-box, width `inputs + 1`), matching circomlib's `Poseidon(n)`.
+///
+/// On-chain the hash runs through the Poseidon syscall; off-chain the same function
+/// computes it natively with `light-poseidon`, so clients and programs agree.
+pub struct Poseidon;
+
+impl Poseidon {
+    /// Largest number of inputs of one hash.
+    pub const MAX_INPUTS: usize = 12;
+
+    /// Hashes field elements, returning the hash in the same byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - There are no inputs or more than `MAX_INPUTS` (`Errors::InvalidPoseidonInput`)
+    /// - An input is not below the scalar field modulus (`Errors::FieldElementOutOfRange`)
+    /// - The hash fails (`Errors::InvalidPoseidonInput`)
+    pub fn hash(inputs: &[[u8; 32]], endianness: Endianness) -> Result<[u8; 32], Errors> {
+        if inputs.is_empty() || inputs.len() > Self::MAX_INPUTS {
+            return Err(Errors::InvalidPoseidonInput);
+        }
+        if !inputs
+            .iter()
+            .all(|input| Self::is_field_element(input, endianness))
+        {
+            return Err(Errors::FieldElementOutOfRange);
+        }
+        let inputs: Vec<&[u8]> = inputs.iter().map(<[u8; 32]>::as_slice).collect();
+        solana_poseidon::hashv(Parameters::Bn254X5, endianness.into(), &inputs)
+            .map(|hash| hash.to_bytes())
+            .map_err(|err| match err {
+                PoseidonSyscallError::InputLargerThanModulus => Errors::FieldElementOutOfRange,
+                _ => Errors::InvalidPoseidonInput,
+            })
+    }
+
+    /// Returns whether `bytes` is below the scalar field modulus.
+    #[must_use]
+    pub fn is_field_element(bytes: &[u8; 32], endianness: Endianness) -> bool {
+        U256::from_be_bytes(&endianness.to_big_endian(bytes))
+            .ensure_below(&U256::BN254_SCALAR_MODULUS)
+            .is_ok()
+    }
+
+    /// Converts a `BigInt` into a field element.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::FieldElementOutOfRange` if `value` is negative or not below the
+    /// scalar field modulus.
+    #[cfg(feature = "client")]
+    pub fn field_element_from_bigint(
+        value: &BigInt,
+        endianness: Endianness,
+    ) -> Result<[u8; 32], Errors> {
+        let (sign, bytes) = value.to_bytes_be();
+        if sign == Sign::Minus || bytes.len() > 32 {
+            return Err(Errors::FieldElementOutOfRange);
+        }
+        let mut element = [0u8; 32];
+        element[32 - bytes.len()..].copy_from_slice(&bytes);
+        U256::from_be_bytes(&element).ensure_below(&U256::BN254_SCALAR_MODULUS)?;
+        if endianness == Endianness::LittleEndian {
+            element.reverse();
+        }
+        Ok(element)
+    }
+
+    /// Converts a field element into a `BigInt`.
+    #[cfg(feature = "client")]
+    #[must_use]
+    pub fn field_element_to_bigint(bytes: &[u8; 32], endianness: Endianness) -> BigInt {
+        Conversions::number_array_to_bigint(bytes, endianness == Endianness::LittleEndian)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::errors::Errors;
+use crate::zk::groth16::{Groth16, Proof, VerifyingKey, G1_LEN, G2_LEN};
+use num_bigint::BigUint;
+use serde_json::Value;
+use std::fmt::Write;
+
+/// A verifying key parsed from a snarkjs `verification_key.json`, owning its points.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnarkjsVerifyingKey {
+    /// `vk_alpha_1`.
+    pub alpha_g1: [u8; G1_LEN],
+    /// `vk_beta_2`.
+    pub beta_g2: [u8; G2_LEN],
+    /// `vk_gamma_2`.
+    pub gamma_g2: [u8; G2_LEN],
+    /// `vk_delta_2`.
+    pub delta_g2: [u8; G2_LEN],
+    /// `IC`.
+    pub ic: Vec<[u8; G1_LEN]>,
+}
+
+impl SnarkjsVerifyingKey {
+    /// Borrows the key for `Groth16::verify`.
+    #[must_use]
+    pub fn as_verifying_key(&self) -> VerifyingKey<'_> {
+        VerifyingKey {
+            alpha_g1: self.alpha_g1,
+            beta_g2: self.beta_g2,
+            gamma_g2: self.gamma_g2,
+            delta_g2: self.delta_g2,
+            ic: &self.ic,
+        }
+    }
+
+    /// Emits Rust source declaring the key as a `VerifyingKey` constant named `name`, for
+    /// embedding in a program.
+    #[must_use]
+    pub fn to_rust(&self, name: &str) -> String {
+        let mut out = format!(
+            "pub const {name}: solana_helpers::zk::groth16::VerifyingKey<'static> =\n    \
+             solana_helpers::zk::groth16::VerifyingKey {{\n"
+        );
+        for (field, bytes) in [
+            ("alpha_g1", self.alpha_g1.as_slice()),
+            ("beta_g2", &self.beta_g2),
+            ("gamma_g2", &self.gamma_g2),
+            ("delta_g2", &self.delta_g2),
+        ] {
+            let _ = write!(out, "        {field}: ");
+            write_bytes(&mut out, 8, bytes);
+            out.push_str(",\n");
+        }
+        out.push_str("        ic: &[\n");
+        for point in &self.ic {
+            out.push_str("            ");
+            write_bytes(&mut out, 12, point);
+            out.push_str(",\n");
+        }
+        out.push_str("        ],\n    };\n");
+        out
+    }
+}
+
+/// Provides conversion of snarkjs / circom Groth16 artifacts over BN254 (`bn128`) into
+/// the big-endian uncompressed layouts of the `alt_bn128` syscalls.
+///
+/// snarkjs writes field elements as decimal strings and points in projective form,
+/// G2 coordinates as `[c0, c1]`; the syscalls expect `x || y` for G1 and
+/// `x_c1 || x_c0 || y_c1 || y_c0` for G2, each element as 32 big-endian bytes.
+pub struct Snarkjs;
+
+impl Snarkjs {
+    /// Parses a `proof.json`, for `Groth16::verify`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidSnarkjsArtifact` if the JSON is not a BN254 Groth16 proof.
+    pub fn proof(json: &str) -> Result<Proof, Errors> {
+        let proof = Self::parse(json)?;
+        Ok(Proof {
+            a: g1(&proof["pi_a"])?,
+            b: g2(&proof["pi_b"])?,
+            c: g1(&proof["pi_c"])?,
+        })
+    }
+
+    /// Parses a `proof.json` and negates `A`, for `Groth16::verify_negated`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidSnarkjsArtifact` if the JSON is not a BN254 Groth16 proof,
+    /// or `Errors::InvalidCurvePoint` if `A` can't be negated.
+    pub fn negated_proof(json: &str) -> Result<Proof, Errors> {
+        let proof = Self::proof(json)?;
+        Ok(Proof {
+            a: Groth16::negate_g1(&proof.a)?,
+            ..proof
+        })
+    }
+
+    /// Parses a `verification_key.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidSnarkjsArtifact` if the JSON is not a BN254 Groth16
+    /// verifying key, or `IC` doesn't hold `nPublic + 1` points.
+    pub fn verifying_key(json: &str) -> Result<SnarkjsVerifyingKey, Errors> {
+        let vk = Self::parse(json)?;
+        let ic = vk["IC"]
+            .as_array()
+            .ok_or(Errors::InvalidSnarkjsArtifact)?
+            .iter()
+            .map(g1)
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(public) = vk["nPublic"].as_u64() {
+            if usize::try_from(public).ok().and_then(|n| n.checked_add(1)) != Some(ic.len()) {
+                return Err(Errors::InvalidSnarkjsArtifact);
+            }
+        }
+        Ok(SnarkjsVerifyingKey {
+            alpha_g1: g1(&vk["vk_alpha_1"])?,
+            beta_g2: g2(&vk["vk_beta_2"])?,
+            gamma_g2: g2(&vk["vk_gamma_2"])?,
+            delta_g2: g2(&vk["vk_delta_2"])?,
+            ic,
+        })
+    }
+
+    /// Parses a `public.json` into big-endian public inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Errors::InvalidSnarkjsArtifact` if the JSON is not an array of decimal
+    /// strings fitting in 32 bytes.
+    pub fn public_inputs(json: &str) -> Result<Vec<[u8; 32]>, Errors> {
+        let inputs: Value =
+            serde_json::from_str(json).map_err(|_| Errors::InvalidSnarkjsArtifact)?;
+        inputs
+            .as_array()
+            .ok_or(Errors::InvalidSnarkjsArtifact)?
+            .iter()
+            .map(field_element)
+            .collect()
+    }
+
+    /// Parses an artifact, rejecting one that declares another protocol or curve.
+    fn parse(json: &str) -> Result<Value, Errors> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|_| Errors::InvalidSnarkjsArtifact)?;
+        let protocol = value["protocol"].as_str().unwrap_or("groth16");
+        let curve = value["curve"].as_str().unwrap_or("bn128");
+        if protocol != "groth16" || curve != "bn128" {
+            return Err(Errors::InvalidSnarkjsArtifact);
+        }
+        Ok(value)
+    }
+}
+
+/// Converts a decimal string to 32 big-endian bytes.
+fn field_element(value: &Value) -> Result<[u8; 32], Errors> {
+    let number = value
+        .as_str()
+        .and_then(|digits| BigUint::parse_bytes(digits.as_bytes(), 10))
+        .ok_or(Errors::InvalidSnarkjsArtifact)?;
+    let bytes = number.to_bytes_be();
+    let mut out = [0u8; 32];
+    let start = out
+        .len()
+        .checked_sub(bytes.len())
+        .ok_or(Errors::InvalidSnarkjsArtifact)?;
+    out[start..].copy_from_slice(&bytes);
+    Ok(out)
+}
+
+/// Returns the affine coordinates of a normalized projective point, or `None` for the
+/// point at infinity.
+fn affine(value: &Value) -> Result<Option<&[Value]>, Errors> {
+    let coordinates = value.as_array().ok_or(Errors::InvalidSnarkjsArtifact)?;
+    match coordinates.as_slice() {
+        [_, _] => Ok(Some(coordinates)),
+        [x_y @ .., z] if x_y.len() == 2 && is_constant(z, "1") => Ok(Some(x_y)),
+        [_, _, z] if is_constant(z, "0") => Ok(None),
+        _ => Err(Errors::InvalidSnarkjsArtifact),
+    }
+}
+
+/// Whether a projective `z`, a decimal string or a `[c0, c1]` pair, is the constant
+/// `digits`.
+fn is_constant(z: &Value, digits: &str) -> bool {
+    match z {
+        Value::String(value) => value == digits,
+        Value::Array(parts) => match parts.as_slice() {
+            [c0, c1] => is_constant(c0, digits) && is_constant(c1, "0"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Converts a snarkjs G1 point to `x || y`.
+fn g1(value: &Value) -> Result<[u8; G1_LEN], Errors> {
+    let mut out = [0u8; G1_LEN];
+    if let Some(coordinates) = affine(value)? {
+        for (chunk, coordinate) in out.chunks_mut(32).zip(coordinates) {
+            chunk.copy_from_slice(&field_element(coordinate)?);
+        }
+    }
+    Ok(out)
+}
+
+/// Converts a snarkjs G2 point to `x_c1 || x_c0 || y_c1 || y_c0`.
+fn g2(value: &Value) -> Result<[u8; G2_LEN], Errors> {
+    let mut out = [0u8; G2_LEN];
+    if let Some(coordinates) = affine(value)? {
+        for (chunk, coordinate) in out.chunks_mut(64).zip(coordinates) {
+            let Some([c0, c1]) = coordinate.as_array().map(Vec::as_slice) else {
+                return Err(Errors::InvalidSnarkjsArtifact);
+            };
+            chunk[..32].copy_from_slice(&field_element(c1)?);
+            chunk[32..].copy_from_slice(&field_element(c0)?);
+        }
+    }
+    Ok(out)
+}
+
+/// Writes `bytes` as a Rust array literal, 16 bytes per line.
+fn write_bytes(out: &mut String, indent: usize, bytes: &[u8]) {
+    out.push_str("[\n");
+    for line in bytes.chunks(16) {
+        let _ = write!(out, "{:width$}", "", width = indent + 4);
+        for byte in line {
+            let _ = write!(out, "0x{byte:02x}, ");
+        }
+        out.pop();
+        out.push('\n');
+    }
+    let _ = write!(out, "{:indent$}]", "");
+}
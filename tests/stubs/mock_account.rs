@@ -0,0 +1,141 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+// Byte offsets mirroring the runtime's serialized account layout, which
+// `AccountInfo::resize` and `AccountInfo::original_data_len` rely on.
+const ORIGINAL_DATA_LEN_OFFSET: usize = 4;
+const KEY_OFFSET: usize = 8;
+const OWNER_OFFSET: usize = 40;
+const LAMPORTS_OFFSET: usize = 72;
+const DATA_LEN_OFFSET: usize = 80;
+const DATA_OFFSET: usize = 88;
+
+/// An account backed by a buffer laid out like the runtime's input serialization,
+/// so `AccountInfo::resize` and `AccountInfo::assign` are safe to call on it.
+pub struct MockAccount {
+    buffer: Vec<u64>,
+}
+
+impl MockAccount {
+    pub fn new(key: Pubkey, owner: Pubkey, lamports: u64, data: &[u8]) -> Self {
+        let bytes = DATA_OFFSET + data.len() + MAX_PERMITTED_DATA_INCREASE;
+        let mut account = Self {
+            buffer: vec![0u64; bytes.div_ceil(8)],
+        };
+        let original_len = u32::try_from(data.len()).unwrap();
+        account.write(ORIGINAL_DATA_LEN_OFFSET, &original_len.to_le_bytes());
+        account.write(KEY_OFFSET, key.as_ref());
+        account.write(OWNER_OFFSET, owner.as_ref());
+        account.write(LAMPORTS_OFFSET, &lamports.to_le_bytes());
+        account.write(DATA_LEN_OFFSET, &(data.len() as u64).to_le_bytes());
+        account.write(DATA_OFFSET, data);
+        account
+    }
+
+    /// A system-owned wallet holding `lamports` and no data.
+    pub fn wallet(key: Pubkey, lamports: u64) -> Self {
+        Self::new(key, solana_system_interface::program::ID, lamports, &[])
+    }
+
+    /// An executable program account, passed to CPIs as the invoked program.
+    pub fn program(key: Pubkey) -> Self {
+        Self::new(key, solana_program::bpf_loader::ID, 1, &[])
+    }
+
+    /// A rent-exempt account owned by `owner` holding the packed `state`.
+    pub fn packed<T: Pack>(key: Pubkey, owner: Pubkey, state: T) -> Self {
+        let mut data = vec![0u8; T::LEN];
+        T::pack(state, &mut data).unwrap();
+        Self::new(
+            key,
+            owner,
+            Rent::default().minimum_balance(data.len()),
+            &data,
+        )
+    }
+
+    /// An initialized SPL token account holding `amount` of `mint` for `owner`.
+    pub fn token_account(key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) -> Self {
+        let state = spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            state: spl_token::state::AccountState::Initialized,
+            ..spl_token::state::Account::default()
+        };
+        Self::packed(key, spl_token::ID, state)
+    }
+
+    /// An initialized SPL token mint with the given supply and decimals.
+    pub fn mint(key: Pubkey, supply: u64, decimals: u8) -> Self {
+        let state = spl_token::state::Mint {
+            supply,
+            decimals,
+            is_initialized: true,
+            ..spl_token::state::Mint::default()
+        };
+        Self::packed(key, spl_token::ID, state)
+    }
+
+    pub fn info(&mut self, is_signer: bool, is_writable: bool) -> AccountInfo<'_> {
+        let executable = self.owner() == solana_program::bpf_loader::ID;
+        let data_len = self.data_len();
+        let base = self.buffer.as_mut_ptr().cast::<u8>();
+        // SAFETY: every offset lies within `buffer`, is suitably aligned for its type
+        // and the returned references share the exclusive borrow of `self`.
+        unsafe {
+            AccountInfo::new(
+                &*base.add(KEY_OFFSET).cast::<Pubkey>(),
+                is_signer,
+                is_writable,
+                &mut *base.add(LAMPORTS_OFFSET).cast::<u64>(),
+                std::slice::from_raw_parts_mut(base.add(DATA_OFFSET), data_len),
+                &*base.add(OWNER_OFFSET).cast::<Pubkey>(),
+                executable,
+                0,
+            )
+        }
+    }
+
+    pub fn owner(&self) -> Pubkey {
+        Pubkey::try_from(self.read(OWNER_OFFSET, 32)).unwrap()
+    }
+
+    pub fn lamports(&self) -> u64 {
+        u64::from_le_bytes(self.read(LAMPORTS_OFFSET, 8).try_into().unwrap())
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.read(DATA_OFFSET, self.data_len())
+    }
+
+    fn data_len(&self) -> usize {
+        let len = u64::from_le_bytes(self.read(DATA_LEN_OFFSET, 8).try_into().unwrap());
+        usize::try_from(len).unwrap()
+    }
+
+    fn bytes(&self) -> &[u8] {
+        // SAFETY: a `u64` buffer is always valid to view as bytes.
+        unsafe {
+            std::slice::from_raw_parts(self.buffer.as_ptr().cast::<u8>(), self.buffer.len() * 8)
+        }
+    }
+
+    fn read(&self, offset: usize, len: usize) -> &[u8] {
+        &self.bytes()[offset..offset + len]
+    }
+
+    fn write(&mut self, offset: usize, src: &[u8]) {
+        // SAFETY: a `u64` buffer is always valid to view as bytes.
+        let bytes = unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buffer.as_mut_ptr().cast::<u8>(),
+                self.buffer.len() * 8,
+            )
+        };
+        bytes[offset..offset + src.len()].copy_from_slice(src);
+    }
+}
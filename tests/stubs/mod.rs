@@ -0,0 +1,5 @@
+// Each integration test crate uses a different subset of these helpers.
+#![allow(dead_code)]
+
+pub mod mock_account;
+pub mod syscall_stubs;
@@ -0,0 +1,228 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::clock::Clock;
+use solana_program::entrypoint::{ProgramResult, SUCCESS};
+use solana_program::instruction::Instruction;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use solana_program::program_utils::limited_deserialize;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_system_interface::error::SystemError;
+use solana_system_interface::instruction::SystemInstruction;
+use solana_system_interface::program as system_program;
+use spl_token::instruction::TokenInstruction;
+use spl_token::state::Account;
+use std::cell::RefCell;
+use std::sync::Once;
+
+/// Program id the stubs treat as the caller when verifying PDA signer seeds.
+pub const TEST_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// Maximum size of serialized system instruction data the stubs accept.
+const MAX_INSTRUCTION_DATA: u64 = 1232;
+
+static INSTALL: Once = Once::new();
+
+thread_local! {
+    /// Programs currently executing, innermost last; PDA signer seeds derive from it.
+    static CALLERS: RefCell<Vec<Pubkey>> = RefCell::new(vec![TEST_PROGRAM_ID]);
+    /// Return data set by the last program that called `set_return_data`.
+    static RETURN_DATA: RefCell<Option<(Pubkey, Vec<u8>)>> = const { RefCell::new(None) };
+}
+
+/// Installs [`CpiStubs`] process-wide. Safe to call from every test.
+pub fn install() {
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(CpiStubs));
+    });
+}
+
+/// Syscall stubs executing System, SPL Token, Token-2022 and Associated Token Account
+/// instructions natively against the `AccountInfo`s handed to `invoke`/`invoke_signed`,
+/// as if [`TEST_PROGRAM_ID`] issued the CPI. Nested CPIs are issued by the program
+/// executing them.
+pub struct CpiStubs;
+
+impl SyscallStubs for CpiStubs {
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        let accounts = instruction_accounts(instruction, account_infos, signers_seeds)?;
+        CALLERS.with_borrow_mut(|callers| callers.push(instruction.program_id));
+        let result = process(instruction, &accounts);
+        CALLERS.with_borrow_mut(Vec::pop);
+        result
+    }
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // SAFETY: the sysvar getter passes a pointer to a `Clock` it owns.
+        unsafe { *var_addr.cast::<Clock>() = Clock::default() };
+        SUCCESS
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        // SAFETY: the sysvar getter passes a pointer to a `Rent` it owns.
+        unsafe { *var_addr.cast::<Rent>() = Rent::default() };
+        SUCCESS
+    }
+
+    fn sol_set_return_data(&self, data: &[u8]) {
+        let program_id = caller();
+        RETURN_DATA.with_borrow_mut(|return_data| *return_data = Some((program_id, data.to_vec())));
+    }
+
+    fn sol_get_return_data(&self) -> Option<(Pubkey, Vec<u8>)> {
+        RETURN_DATA.with_borrow(Clone::clone)
+    }
+}
+
+/// Returns the program currently executing.
+fn caller() -> Pubkey {
+    CALLERS.with_borrow(|callers| callers[callers.len() - 1])
+}
+
+/// Runs `instruction` with the native processor of its program.
+fn process(instruction: &Instruction, accounts: &[AccountInfo]) -> ProgramResult {
+    if instruction.program_id == system_program::ID {
+        process_system(instruction, accounts)
+    } else if instruction.program_id == spl_token::ID {
+        spl_token::processor::Processor::process(&spl_token::ID, accounts, &instruction.data)?;
+        // The token processors set return data through `solana_cpi`, which is a no-op
+        // off-chain, so provide the account size `GetAccountDataSize` returns.
+        if let Ok(TokenInstruction::GetAccountDataSize) =
+            TokenInstruction::unpack(&instruction.data)
+        {
+            RETURN_DATA.with_borrow_mut(|return_data| {
+                *return_data = Some((spl_token::ID, Account::LEN.to_le_bytes().to_vec()));
+            });
+        }
+        Ok(())
+    } else if instruction.program_id == spl_token_2022::ID {
+        spl_token_2022::processor::Processor::process(
+            &spl_token_2022::ID,
+            accounts,
+            &instruction.data,
+        )
+    } else if instruction.program_id == spl_associated_token_account::ID {
+        spl_associated_token_account::processor::process_instruction(
+            &spl_associated_token_account::ID,
+            accounts,
+            &instruction.data,
+        )
+    } else {
+        Err(ProgramError::IncorrectProgramId)
+    }
+}
+
+/// Resolves the instruction's account metas against the supplied infos, applying
+/// the runtime's privilege rules: a key signs if its info signed or one of the
+/// seed sets derives it from the calling program, and privileges are merged
+/// across duplicate metas.
+fn instruction_accounts<'a>(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo<'a>],
+    signers_seeds: &[&[&[u8]]],
+) -> Result<Vec<AccountInfo<'a>>, ProgramError> {
+    let program_id = caller();
+    let signers = signers_seeds
+        .iter()
+        .map(|seeds| Pubkey::create_program_address(seeds, &program_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    instruction
+        .accounts
+        .iter()
+        .map(|meta| {
+            let info = account_infos
+                .iter()
+                .find(|info| *info.key == meta.pubkey)
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            let metas = instruction
+                .accounts
+                .iter()
+                .filter(|m| m.pubkey == meta.pubkey);
+            let (is_signer, is_writable) = metas.fold((false, false), |(s, w), m| {
+                (s || m.is_signer, w || m.is_writable)
+            });
+            if is_signer && !info.is_signer && !signers.contains(info.key) {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            if is_writable && !info.is_writable {
+                return Err(ProgramError::InvalidArgument);
+            }
+            let mut info = info.clone();
+            info.is_signer = is_signer;
+            info.is_writable = is_writable;
+            Ok(info)
+        })
+        .collect()
+}
+
+fn process_system(instruction: &Instruction, accounts: &[AccountInfo]) -> ProgramResult {
+    let account = |index: usize| {
+        accounts
+            .get(index)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    };
+    match limited_deserialize(&instruction.data, MAX_INSTRUCTION_DATA)
+        .map_err(|_| ProgramError::InvalidInstructionData)?
+    {
+        SystemInstruction::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => {
+            let to = account(1)?;
+            if to.lamports() > 0 {
+                return Err(system_error(SystemError::AccountAlreadyInUse));
+            }
+            transfer(account(0)?, to, lamports)?;
+            allocate(to, space)?;
+            to.assign(&owner);
+            Ok(())
+        }
+        SystemInstruction::Transfer { lamports } => transfer(account(0)?, account(1)?, lamports),
+        SystemInstruction::Assign { owner } => {
+            let target = account(0)?;
+            if *target.owner != system_program::ID {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            target.assign(&owner);
+            Ok(())
+        }
+        SystemInstruction::Allocate { space } => allocate(account(0)?, space),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn allocate(account: &AccountInfo, space: u64) -> ProgramResult {
+    if !account.data_is_empty() || *account.owner != system_program::ID {
+        return Err(system_error(SystemError::AccountAlreadyInUse));
+    }
+    let space = usize::try_from(space).map_err(|_| ProgramError::InvalidArgument)?;
+    account.resize(space)
+}
+
+fn transfer(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> ProgramResult {
+    if !from.data_is_empty() || *from.owner != system_program::ID {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let post_from = from
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or_else(|| system_error(SystemError::ResultWithNegativeLamports))?;
+    let post_to = to
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **from.try_borrow_mut_lamports()? = post_from;
+    **to.try_borrow_mut_lamports()? = post_to;
+    Ok(())
+}
+
+fn system_error(error: SystemError) -> ProgramError {
+    ProgramError::Custom(error as u32)
+}
@@ -0,0 +1,69 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::account_context::AccountContext;
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+use stubs::mock_account::MockAccount;
+
+struct Accounts<'a, 'info> {
+    payer: &'a AccountInfo<'info>,
+    vault: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> Accounts<'a, 'info> {
+    fn load(ctx: &mut AccountContext<'a, 'info>) -> Result<Self, Errors> {
+        Ok(Self {
+            payer: ctx.next_account()?,
+            vault: ctx.next_account()?,
+        })
+    }
+}
+
+#[test]
+fn hands_out_accounts_in_order() {
+    let program_id = Pubkey::new_unique();
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10);
+    let mut vault = MockAccount::wallet(Pubkey::new_unique(), 20);
+    let mut extra = MockAccount::wallet(Pubkey::new_unique(), 30);
+    let infos = [
+        payer.info(true, true),
+        vault.info(false, true),
+        extra.info(false, false),
+    ];
+
+    let mut ctx = AccountContext::new(&program_id, &infos);
+    let accounts = Accounts::load(&mut ctx).unwrap();
+
+    assert_eq!(accounts.payer.key, infos[0].key);
+    assert_eq!(accounts.vault.key, infos[1].key);
+    assert_eq!(ctx.remaining().len(), 1);
+    assert_eq!(ctx.find(infos[2].key).unwrap().lamports(), 30);
+    assert_eq!(ctx.program_id(), &program_id);
+    assert!(matches!(
+        ctx.next_accounts(2),
+        Err(Errors::NotEnoughAccountKeys)
+    ));
+    assert_eq!(ctx.next_accounts(1).unwrap().len(), 1);
+    assert!(matches!(
+        ctx.next_account(),
+        Err(Errors::NotEnoughAccountKeys)
+    ));
+}
+
+#[test]
+fn cloned_accounts_share_state() {
+    let program_id = Pubkey::new_unique();
+    let mut vault = MockAccount::wallet(Pubkey::new_unique(), 20);
+    let infos = [vault.info(false, true)];
+
+    let ctx = AccountContext::new(&program_id, &infos);
+    let clone = ctx.clone_account(0).unwrap();
+    **clone.try_borrow_mut_lamports().unwrap() = 5;
+
+    assert_eq!(ctx.get(0).unwrap().lamports(), 5);
+    assert!(matches!(
+        ctx.clone_account(1),
+        Err(Errors::NotEnoughAccountKeys)
+    ));
+}
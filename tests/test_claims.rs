@@ -0,0 +1,85 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::claims::ClaimBitmap;
+use solana_program::pubkey::Pubkey;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+type Claims = ClaimBitmap<16>;
+
+#[test]
+fn claims_indexes_across_chunks() {
+    install();
+    let claims = Claims::new(TEST_PROGRAM_ID, Pubkey::new_unique());
+    let mut first = MockAccount::wallet(claims.chunk_pda(0).0, 0);
+    let mut second = MockAccount::wallet(claims.chunk_address(20), 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let (first, second, payer, system) = (
+        first.info(false, true),
+        second.info(false, true),
+        payer.info(true, true),
+        system.info(false, false),
+    );
+
+    claims.create_chunk(&first, &system, &payer, 0).unwrap();
+    claims.create_chunk(&second, &system, &payer, 1).unwrap();
+    assert_eq!(first.data_len(), Claims::CHUNK_SPACE);
+    assert_eq!(first.data.borrow()[0], claims.chunk_pda(0).1);
+
+    claims.claim(&first, 15).unwrap();
+    claims.claim(&second, 16).unwrap();
+    assert!(matches!(
+        claims.claim(&first, 15),
+        Err(Errors::AlreadyClaimed)
+    ));
+    assert!(claims.is_claimed(&second, 16).unwrap());
+    assert!(!claims.is_claimed(&second, 17).unwrap());
+    assert!(matches!(
+        claims.claim(&first, 16),
+        Err(Errors::WrongPdaAddress)
+    ));
+
+    let bump = first.data.borrow()[0];
+    first.data.borrow_mut()[0] = bump.wrapping_sub(1);
+    assert!(matches!(
+        claims.claim(&first, 0),
+        Err(Errors::WrongPdaAddress)
+    ));
+}
+
+#[test]
+fn chunk_address_is_scoped_by_namespace() {
+    let program_id = Pubkey::new_unique();
+    let namespace = Pubkey::new_unique();
+    let claims = Claims::new(program_id, namespace);
+
+    assert_eq!(Claims::chunk(31), 1);
+    assert_eq!(claims.chunk_address(16), claims.chunk_address(31));
+    assert_ne!(claims.chunk_address(15), claims.chunk_address(16));
+    assert_eq!(
+        claims.chunk_address(40),
+        Pubkey::find_program_address(
+            &[b"claims", namespace.as_ref(), &2u64.to_le_bytes()],
+            &program_id
+        )
+        .0
+    );
+    assert_ne!(
+        Claims::new(program_id, Pubkey::new_unique()).chunk_address(0),
+        claims.chunk_address(0)
+    );
+}
+
+#[test]
+fn rejects_chunk_not_owned_by_program() {
+    let claims = Claims::new(TEST_PROGRAM_ID, Pubkey::new_unique());
+    let mut chunk = MockAccount::new(claims.chunk_address(0), Pubkey::new_unique(), 1, &[0; 3]);
+
+    assert!(matches!(
+        claims.claim(&chunk.info(false, true), 0),
+        Err(Errors::InvalidAccountOwner)
+    ));
+}
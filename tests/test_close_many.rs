@@ -0,0 +1,117 @@
+mod stubs;
+
+use solana_helpers::helpers::closers::{CloseSkipReason, Closers};
+use solana_helpers::helpers::pda_signer::PdaSigner;
+use solana_program::pubkey::Pubkey;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+#[test]
+fn closes_mixed_accounts_and_reports_skips() {
+    install();
+    let authority_signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"authority"]);
+    let system_signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"expired"]);
+    let authority_key = *authority_signer.address();
+    let system_pda = *system_signer.address();
+
+    let mut program_pda = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 1_000, &[9; 8]);
+    let mut live_pda = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 2_000, &[7; 8]);
+    let mut empty_token =
+        MockAccount::token_account(Pubkey::new_unique(), Pubkey::new_unique(), authority_key, 0);
+    let mut full_token =
+        MockAccount::token_account(Pubkey::new_unique(), Pubkey::new_unique(), authority_key, 5);
+    let mut foreign_token = MockAccount::token_account(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        0,
+    );
+    let mut system_account = MockAccount::wallet(system_pda, 3_000);
+    let mut unsigned_wallet = MockAccount::wallet(Pubkey::new_unique(), 4_000);
+    let mut foreign_program = MockAccount::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, &[]);
+    let mut read_only = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 1, &[]);
+    let mut recipient = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut authority = MockAccount::wallet(authority_key, 0);
+    let mut system = MockAccount::program(system_program::ID);
+    let token_refund = empty_token.lamports();
+
+    let accounts = [
+        program_pda.info(false, true),
+        live_pda.info(false, true),
+        empty_token.info(false, true),
+        full_token.info(false, true),
+        foreign_token.info(false, true),
+        system_account.info(false, true),
+        unsigned_wallet.info(false, true),
+        foreign_program.info(false, true),
+        read_only.info(false, false),
+    ];
+    let recipient_info = recipient.info(false, true);
+    let report = Closers::close_many(
+        &accounts,
+        &recipient_info,
+        &authority.info(false, false),
+        &system.info(false, false),
+        &TEST_PROGRAM_ID,
+        &[&authority_signer],
+        |account| Ok(account.try_borrow_data()?[..8] == [9; 8]),
+        |key| (*key == system_pda).then(|| system_signer.clone()),
+    )
+    .unwrap();
+
+    assert_eq!(
+        report.closed,
+        vec![*accounts[0].key, *accounts[2].key, *accounts[5].key]
+    );
+    assert_eq!(report.refunded, 1_000 + token_refund + 3_000);
+    assert_eq!(accounts[1].lamports(), 2_000);
+    assert_eq!(recipient_info.lamports(), report.refunded);
+
+    let reasons: Vec<_> = report.skipped.iter().map(|(_, reason)| reason).collect();
+    assert!(matches!(
+        reasons.as_slice(),
+        [
+            CloseSkipReason::NotClosable,
+            CloseSkipReason::NonEmptyTokenAccount,
+            CloseSkipReason::WrongAuthority,
+            CloseSkipReason::MissingSignerSeeds,
+            CloseSkipReason::UnsupportedOwner,
+            CloseSkipReason::NotWritable,
+        ]
+    ));
+}
+
+#[test]
+fn skips_duplicates_and_recipient() {
+    install();
+    let mut program_pda = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 1_000, &[]);
+    let mut recipient = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut authority = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut system = MockAccount::program(system_program::ID);
+
+    let pda_info = program_pda.info(false, true);
+    let recipient_info = recipient.info(false, true);
+    let accounts = [pda_info.clone(), pda_info, recipient_info.clone()];
+    let report = Closers::close_many(
+        &accounts,
+        &recipient_info,
+        &authority.info(false, false),
+        &system.info(false, false),
+        &TEST_PROGRAM_ID,
+        &[],
+        |_| Ok(true),
+        |_| None,
+    )
+    .unwrap();
+
+    assert_eq!(report.closed.len(), 1);
+    assert_eq!(report.refunded, 1_000);
+    assert!(matches!(
+        report.skipped.as_slice(),
+        [
+            (_, CloseSkipReason::AlreadyClosed),
+            (_, CloseSkipReason::IsRecipient)
+        ]
+    ));
+}
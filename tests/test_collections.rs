@@ -0,0 +1,142 @@
+mod stubs;
+
+use borsh::BorshDeserialize;
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::collections::{AccountVec, Bitmap, RingBuffer, SortedMap};
+use solana_helpers::helpers::fixed::BoundedVec;
+use solana_helpers::helpers::space::AccountSpace;
+use solana_program::pubkey::Pubkey;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::TEST_PROGRAM_ID;
+
+#[test]
+fn account_vec_push_remove_and_bounds() {
+    let mut data = [0u8; AccountVec::<u64, 3, &[u8]>::SPACE];
+    let mut list = AccountVec::<u64, 3, _>::new(&mut data[..]).unwrap();
+
+    for item in [10, 20, 30] {
+        list.push(item).unwrap();
+    }
+    assert!(matches!(list.push(40), Err(Errors::FixedCapacityExceeded)));
+    assert_eq!(list.remove(0).unwrap(), 10);
+    assert_eq!(list.iter().collect::<Vec<_>>(), vec![20, 30]);
+    assert_eq!(list.swap_remove(0).unwrap(), 20);
+    assert!(matches!(list.remove(1), Err(Errors::IndexOutOfBounds)));
+    assert!(matches!(list.set(1, 5), Err(Errors::IndexOutOfBounds)));
+    assert_eq!(list.pop(), Some(30));
+    assert_eq!(list.pop(), None);
+    assert!(data.iter().all(|byte| *byte == 0));
+
+    data[0] = 4;
+    assert!(matches!(
+        AccountVec::<u64, 3, _>::new(&data[..]),
+        Err(Errors::InvalidAccountData)
+    ));
+    assert!(matches!(
+        AccountVec::<u64, 3, _>::new(&data[..8]),
+        Err(Errors::InvalidAccountData)
+    ));
+}
+
+#[test]
+fn account_vec_edits_a_trailing_bounded_vec() {
+    type State = (u64, BoundedVec<u32, 4>);
+    let state: State = (9, vec![7, 8].try_into().unwrap());
+    let mut data = borsh::to_vec(&state).unwrap();
+    data.resize(8 + AccountVec::<u32, 4, &[u8]>::SPACE, 0);
+    assert_eq!(
+        AccountVec::<u32, 4, &[u8]>::SPACE,
+        <BoundedVec<u32, 4> as AccountSpace>::MAX_SPACE
+    );
+
+    let mut list = AccountVec::<u32, 4, _>::new(&mut data[8..]).unwrap();
+    assert_eq!(list.to_bounded_vec().unwrap(), state.1);
+    list.push(10).unwrap();
+    let (header, items) = State::deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!((header, items.to_vec()), (9, vec![7, 8, 10]));
+
+    let mut list = AccountVec::<u32, 4, _>::new(&mut data[8..]).unwrap();
+    list.assign(&vec![1].try_into().unwrap());
+    assert_eq!(list.iter().collect::<Vec<_>>(), vec![1]);
+    assert!(data[8 + 8..].iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn sorted_map_keeps_keys_ordered() {
+    let mut data = [0u8; SortedMap::<u32, u64, 3, &[u8]>::SPACE];
+    let mut map = SortedMap::<u32, u64, 3, _>::new(&mut data[..]).unwrap();
+
+    assert_eq!(map.insert(30, 3).unwrap(), None);
+    assert_eq!(map.insert(10, 1).unwrap(), None);
+    assert_eq!(map.insert(20, 2).unwrap(), None);
+    assert_eq!(map.insert(20, 22).unwrap(), Some(2));
+    assert!(matches!(
+        map.insert(40, 4),
+        Err(Errors::FixedCapacityExceeded)
+    ));
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        vec![(10, 1), (20, 22), (30, 3)]
+    );
+    assert_eq!(map.remove(&10), Some(1));
+    assert_eq!(map.remove(&10), None);
+    assert_eq!(map.get(&30), Some(3));
+    assert!(!map.contains_key(&10));
+    assert_eq!(map.len(), 2);
+}
+
+#[test]
+fn bitmap_test_and_set() {
+    let mut data = [0u8; Bitmap::<10, &[u8]>::SPACE];
+    let mut bitmap = Bitmap::<10, _>::new(&mut data[..]).unwrap();
+
+    assert!(!bitmap.test_and_set(9).unwrap());
+    assert!(bitmap.test_and_set(9).unwrap());
+    bitmap.set(0).unwrap();
+    assert_eq!(bitmap.count_ones(), 2);
+    bitmap.unset(0).unwrap();
+    assert!(!bitmap.get(0).unwrap());
+    assert!(matches!(bitmap.set(10), Err(Errors::IndexOutOfBounds)));
+    assert_eq!(data, [0, 0b10]);
+}
+
+#[test]
+fn ring_buffer_overwrites_oldest() {
+    let mut data = [0u8; RingBuffer::<u16, 3, &[u8]>::SPACE];
+    let mut ring = RingBuffer::<u16, 3, _>::new(&mut data[..]).unwrap();
+
+    for item in 1..=3 {
+        assert_eq!(ring.push(item), None);
+    }
+    assert!(ring.is_full());
+    assert_eq!(ring.push(4), Some(1));
+    assert_eq!(ring.push(5), Some(2));
+    assert_eq!(ring.iter().collect::<Vec<_>>(), vec![3, 4, 5]);
+    assert_eq!(ring.pop_front(), Some(3));
+    assert_eq!(ring.get(0), Some(4));
+    assert_eq!(ring.get(2), None);
+    ring.push(6);
+    assert_eq!(ring.iter().collect::<Vec<_>>(), vec![4, 5, 6]);
+}
+
+#[test]
+fn loads_collections_from_account_data() {
+    let space = 8 + AccountVec::<Pubkey, 2, &[u8]>::SPACE;
+    let mut account = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 1, &vec![0; space]);
+    let info = account.info(false, true);
+    let key = Pubkey::new_unique();
+
+    {
+        let mut list = AccountVec::<Pubkey, 2, _>::load_mut(&info, 8).unwrap();
+        list.push(key).unwrap();
+        assert!(info.try_borrow_data().is_err());
+    }
+    let list = AccountVec::<Pubkey, 2, _>::load(&info, 8).unwrap();
+    assert_eq!(list.get(0), Some(key));
+    assert!(AccountVec::<Pubkey, 2, _>::load(&info, 9).is_err());
+    drop(list);
+    drop(info);
+
+    assert_eq!(&account.data()[8..12], &1u32.to_le_bytes());
+    assert_eq!(&account.data()[12..44], key.as_ref());
+}
@@ -0,0 +1,458 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::closers::Closers;
+use solana_helpers::helpers::generic::Generic;
+use solana_helpers::helpers::pda_signer::PdaSigner;
+use solana_helpers::helpers::transfers::Transfers;
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+fn is_program_error(err: &Errors, expected: &ProgramError) -> bool {
+    matches!(err, Errors::ProgramError(msg) if *msg == expected.to_string())
+}
+
+#[test]
+fn create_pda_account_allocates_and_assigns() {
+    install();
+    let seeds: &[&[u8]] = &[b"pda"];
+    let (pda, _) = Pubkey::find_program_address(seeds, &TEST_PROGRAM_ID);
+    let mut target = MockAccount::wallet(pda, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+
+    Generic::create_pda_account(
+        &target.info(false, true),
+        &system.info(false, false),
+        &payer.info(true, true),
+        42,
+        &TEST_PROGRAM_ID,
+        seeds,
+    )
+    .unwrap();
+
+    let rent = Rent::default().minimum_balance(42);
+    assert_eq!(target.owner(), TEST_PROGRAM_ID);
+    assert_eq!(target.data(), &[0u8; 42]);
+    assert_eq!(target.lamports(), rent);
+    assert_eq!(payer.lamports(), 10_000_000_000 - rent);
+}
+
+#[test]
+fn create_pda_account_rejects_wrong_address() {
+    install();
+    let mut target = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+
+    let err = Generic::create_pda_account(
+        &target.info(false, true),
+        &system.info(false, false),
+        &payer.info(true, true),
+        42,
+        &TEST_PROGRAM_ID,
+        &[b"pda"],
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::WrongPdaAddress));
+    assert_eq!(target.owner(), system_program::ID);
+}
+
+#[test]
+fn create_pda_account_with_signer_uses_its_pda() {
+    install();
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"pda"]);
+    let mut target = MockAccount::wallet(*signer.address(), 0);
+    let mut other = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let (payer, system) = (payer.info(true, true), system.info(false, false));
+
+    let err = Generic::create_pda_account_with_signer(
+        &other.info(false, true),
+        &system,
+        &payer,
+        42,
+        &signer,
+    )
+    .unwrap_err();
+    assert!(matches!(err, Errors::WrongPdaAddress));
+
+    Generic::create_pda_account_with_signer(
+        &target.info(false, true),
+        &system,
+        &payer,
+        42,
+        &signer,
+    )
+    .unwrap();
+    assert_eq!(target.owner(), TEST_PROGRAM_ID);
+    assert_eq!(target.data(), &[0u8; 42]);
+}
+
+#[test]
+fn burn_tokens_with_pda_authority() {
+    install();
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"authority"]);
+    let authority = *signer.address();
+    let mint_key = Pubkey::new_unique();
+    let mut mint = MockAccount::mint(mint_key, 100, 6);
+    let mut account = MockAccount::token_account(Pubkey::new_unique(), mint_key, authority, 100);
+    let mut owner = MockAccount::wallet(authority, 0);
+    let mut token_program = MockAccount::program(spl_token::ID);
+
+    Generic::burn_tokens(
+        token_program.info(false, false),
+        account.info(false, true),
+        mint.info(false, true),
+        owner.info(false, false),
+        40,
+        &[],
+        &[&signer],
+    )
+    .unwrap();
+
+    let state = spl_token::state::Account::unpack(account.data()).unwrap();
+    assert_eq!(state.amount, 60);
+    assert_eq!(
+        spl_token::state::Mint::unpack(mint.data()).unwrap().supply,
+        60
+    );
+}
+
+#[test]
+fn burn_tokens_without_signer_seeds_fails() {
+    install();
+    let (authority, _) = Pubkey::find_program_address(&[b"authority"], &TEST_PROGRAM_ID);
+    let mint_key = Pubkey::new_unique();
+    let mut mint = MockAccount::mint(mint_key, 100, 6);
+    let mut account = MockAccount::token_account(Pubkey::new_unique(), mint_key, authority, 100);
+    let mut owner = MockAccount::wallet(authority, 0);
+    let mut token_program = MockAccount::program(spl_token::ID);
+
+    let err = Generic::burn_tokens(
+        token_program.info(false, false),
+        account.info(false, true),
+        mint.info(false, true),
+        owner.info(false, false),
+        40,
+        &[],
+        &[],
+    )
+    .unwrap_err();
+
+    assert!(is_program_error(
+        &err,
+        &ProgramError::MissingRequiredSignature
+    ));
+    let state = spl_token::state::Account::unpack(account.data()).unwrap();
+    assert_eq!(state.amount, 100);
+}
+
+#[test]
+fn close_token_account_with_pda_authority() {
+    install();
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"authority"]);
+    let authority = *signer.address();
+    let mut account =
+        MockAccount::token_account(Pubkey::new_unique(), Pubkey::new_unique(), authority, 0);
+    let mut destination = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut owner = MockAccount::wallet(authority, 0);
+    let refund = account.lamports();
+
+    Closers::close_token_account(
+        account.info(false, true),
+        destination.info(false, true),
+        owner.info(false, false),
+        &[],
+        &[&signer],
+    )
+    .unwrap();
+
+    assert_eq!(account.lamports(), 0);
+    assert_eq!(destination.lamports(), refund);
+    assert_eq!(account.owner(), system_program::ID);
+    assert!(account.data().iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn transfer_token_rejects_fake_token_program() {
+    install();
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut mint_account = MockAccount::mint(mint, 10, 0);
+    let mut from = MockAccount::token_account(Pubkey::new_unique(), mint, owner, 10);
+    let mut to = MockAccount::token_account(Pubkey::new_unique(), mint, owner, 0);
+    let mut fake_program = MockAccount::program(Pubkey::new_unique());
+    let mut authority = MockAccount::wallet(owner, 0);
+
+    let err = Transfers::transfer_token(
+        from.info(false, true),
+        mint_account.info(false, false),
+        to.info(false, true),
+        fake_program.info(false, false),
+        authority.info(true, false),
+        10,
+        0,
+        &[],
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::InvalidProgramId));
+}
+
+#[test]
+fn transfer_token_through_token_2022() {
+    install();
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let state = spl_token::state::Account {
+        mint,
+        owner,
+        amount: 10,
+        state: spl_token::state::AccountState::Initialized,
+        ..spl_token::state::Account::default()
+    };
+    let mut from = MockAccount::packed(Pubkey::new_unique(), spl_token_2022::ID, state);
+    let mut to = MockAccount::packed(
+        Pubkey::new_unique(),
+        spl_token_2022::ID,
+        spl_token::state::Account { amount: 0, ..state },
+    );
+    let mut mint_account = MockAccount::packed(
+        mint,
+        spl_token_2022::ID,
+        spl_token::state::Mint {
+            supply: 10,
+            decimals: 6,
+            is_initialized: true,
+            ..spl_token::state::Mint::default()
+        },
+    );
+    let mut token_program = MockAccount::program(spl_token_2022::ID);
+    let mut authority = MockAccount::wallet(owner, 0);
+    let (from, mint_info, to_info, token_program, authority) = (
+        from.info(false, true),
+        mint_account.info(false, false),
+        to.info(false, true),
+        token_program.info(false, false),
+        authority.info(true, false),
+    );
+
+    Transfers::transfer_token(
+        from.clone(),
+        mint_info.clone(),
+        to_info.clone(),
+        token_program.clone(),
+        authority.clone(),
+        4,
+        6,
+        &[],
+    )
+    .unwrap();
+    let err = Transfers::transfer_token(
+        from,
+        mint_info,
+        to_info.clone(),
+        token_program,
+        authority,
+        4,
+        9,
+        &[],
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::ProgramError(_)));
+    assert_eq!(
+        spl_token::state::Account::unpack(&to_info.data.borrow())
+            .unwrap()
+            .amount,
+        4
+    );
+}
+
+#[test]
+fn system_helpers_reject_fake_system_program() {
+    install();
+    let seeds: &[&[u8]] = &[b"pda"];
+    let (pda, _) = Pubkey::find_program_address(seeds, &TEST_PROGRAM_ID);
+    let mut target = MockAccount::wallet(pda, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut fake_program = MockAccount::program(Pubkey::new_unique());
+    let (payer, target, fake_program) = (
+        payer.info(true, true),
+        target.info(false, true),
+        fake_program.info(false, false),
+    );
+
+    let err =
+        Generic::create_pda_account(&target, &fake_program, &payer, 42, &TEST_PROGRAM_ID, seeds)
+            .unwrap_err();
+    assert!(matches!(err, Errors::InvalidProgramId));
+
+    let err = Transfers::transfer_sol(payer.clone(), target, fake_program, 1).unwrap_err();
+    assert!(matches!(err, Errors::InvalidProgramId));
+    assert_eq!(payer.lamports(), 10_000_000_000);
+}
+
+#[test]
+fn create_pda_account_survives_prefunding() {
+    install();
+    let seeds: &[&[u8]] = &[b"prefunded"];
+    let (pda, _) = Pubkey::find_program_address(seeds, &TEST_PROGRAM_ID);
+    let mut target = MockAccount::wallet(pda, 1_000);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+
+    Generic::create_pda_account(
+        &target.info(false, true),
+        &system.info(false, false),
+        &payer.info(true, true),
+        42,
+        &TEST_PROGRAM_ID,
+        seeds,
+    )
+    .unwrap();
+
+    let rent = Rent::default().minimum_balance(42);
+    assert_eq!(target.owner(), TEST_PROGRAM_ID);
+    assert_eq!(target.data(), &[0u8; 42]);
+    assert_eq!(target.lamports(), rent);
+    assert_eq!(payer.lamports(), 10_000_000_000 - (rent - 1_000));
+}
+
+#[test]
+fn create_account_with_minimal_balance_survives_prefunding() {
+    install();
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"minimal"]);
+    let pda = *signer.address();
+    let rent = Rent::default().minimum_balance(10);
+    let mut account = MockAccount::wallet(pda, rent + 5);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+
+    Generic::create_account_with_minimal_balance(
+        &account.info(false, true),
+        10,
+        &TEST_PROGRAM_ID,
+        &payer.info(true, true),
+        &system.info(false, false),
+        &[&signer],
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(account.owner(), TEST_PROGRAM_ID);
+    assert_eq!(account.data(), &[0u8; 10]);
+    assert_eq!(account.lamports(), rent + 5);
+    assert_eq!(payer.lamports(), 10_000_000_000);
+}
+
+#[test]
+fn create_pda_account_rejects_account_in_use() {
+    install();
+    let seeds: &[&[u8]] = &[b"in-use"];
+    let (pda, _) = Pubkey::find_program_address(seeds, &TEST_PROGRAM_ID);
+    let mut target = MockAccount::new(pda, TEST_PROGRAM_ID, 1_000_000, &[1, 2, 3]);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+
+    let err = Generic::create_pda_account(
+        &target.info(false, true),
+        &system.info(false, false),
+        &payer.info(true, true),
+        42,
+        &TEST_PROGRAM_ID,
+        seeds,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::AccountAlreadyInUse));
+    assert_eq!(target.data(), &[1, 2, 3]);
+}
+
+#[test]
+fn close_account_owned_by_program() {
+    install();
+    let mut from = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 2_000_000, &[7; 16]);
+    let mut to = MockAccount::wallet(Pubkey::new_unique(), 1);
+    let mut system = MockAccount::program(system_program::ID);
+
+    Closers::close_account(
+        &from.info(false, true),
+        &to.info(false, true),
+        &system.info(false, false),
+        &TEST_PROGRAM_ID,
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(from.lamports(), 0);
+    assert!(from.data().is_empty());
+    assert_eq!(from.owner(), system_program::ID);
+    assert_eq!(to.lamports(), 2_000_001);
+}
+
+#[test]
+fn close_account_owned_by_system_program() {
+    install();
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"system-pda"]);
+    let pda = *signer.address();
+    let mut from = MockAccount::wallet(pda, 2_000_000);
+    let mut to = MockAccount::wallet(Pubkey::new_unique(), 1);
+    let mut system = MockAccount::program(system_program::ID);
+    let (from_info, to_info, system_info) = (
+        from.info(false, true),
+        to.info(false, true),
+        system.info(false, false),
+    );
+
+    let err = Closers::close_account(&from_info, &to_info, &system_info, &TEST_PROGRAM_ID, &[])
+        .unwrap_err();
+    assert!(is_program_error(
+        &err,
+        &ProgramError::MissingRequiredSignature
+    ));
+
+    Closers::close_account(
+        &from_info,
+        &to_info,
+        &system_info,
+        &TEST_PROGRAM_ID,
+        &[&signer],
+    )
+    .unwrap();
+
+    assert_eq!(from.lamports(), 0);
+    assert_eq!(to.lamports(), 2_000_001);
+}
+
+#[test]
+fn close_account_rejects_foreign_owner() {
+    install();
+    let mut from = MockAccount::token_account(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        0,
+    );
+    let mut to = MockAccount::wallet(Pubkey::new_unique(), 1);
+    let mut system = MockAccount::program(system_program::ID);
+    let lamports = from.lamports();
+
+    let err = Closers::close_account(
+        &from.info(false, true),
+        &to.info(false, true),
+        &system.info(false, false),
+        &TEST_PROGRAM_ID,
+        &[],
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::UnsupportedAccountOwner));
+    assert_eq!(from.lamports(), lamports);
+}
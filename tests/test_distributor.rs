@@ -0,0 +1,297 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::distributor::{
+    ClaimArgs, DistributorAccounts, DistributorParams, MerkleDistributor,
+};
+use solana_helpers::helpers::loaders::TokenAccountLoader;
+use solana_helpers::helpers::merkle::{Merkle, MerkleHash, MerkleTree, ProofOrder};
+use solana_helpers::helpers::transfers::Transfers;
+use solana_program::pubkey::Pubkey;
+use solana_system_interface::program as system_program;
+use spl_associated_token_account::get_associated_token_address;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+const DISTRIBUTOR: MerkleDistributor<64> = MerkleDistributor::new(
+    TEST_PROGRAM_ID,
+    Merkle::new(MerkleHash::Keccak256, ProofOrder::Indexed),
+);
+
+struct Airdrop {
+    claimants: Vec<(Pubkey, u64)>,
+    tree: MerkleTree,
+}
+
+impl Airdrop {
+    fn new(amounts: &[u64]) -> Self {
+        let claimants: Vec<_> = amounts
+            .iter()
+            .map(|amount| (Pubkey::new_unique(), *amount))
+            .collect();
+        let leaves = claimants
+            .iter()
+            .zip(0..)
+            .map(|((claimant, amount), index)| DISTRIBUTOR.leaf(index, claimant, *amount))
+            .collect();
+        let tree = MerkleTree::new(
+            Merkle::new(MerkleHash::Keccak256, ProofOrder::Indexed),
+            leaves,
+        );
+        Self { claimants, tree }
+    }
+}
+
+#[test]
+fn creates_claims_and_rejects_double_claims() {
+    install();
+    let airdrop = Airdrop::new(&[100, 250, 75]);
+    let base = Pubkey::new_unique();
+    let (distributor_key, _) = DISTRIBUTOR.address(&base);
+    let mint_key = Pubkey::new_unique();
+    let vault_key = get_associated_token_address(&distributor_key, &mint_key);
+    let chunk_key = DISTRIBUTOR.claims(&distributor_key).chunk_address(0);
+    let (claimant_key, amount) = airdrop.claimants[1];
+    let funder_key = Pubkey::new_unique();
+
+    let mut distributor = MockAccount::wallet(distributor_key, 0);
+    let mut vault = MockAccount::wallet(vault_key, 0);
+    let mut mint = MockAccount::mint(mint_key, 1_000, 0);
+    let mut chunk = MockAccount::wallet(chunk_key, 0);
+    let mut claimant = MockAccount::wallet(claimant_key, 0);
+    let mut recipient = MockAccount::token_account(Pubkey::new_unique(), mint_key, claimant_key, 0);
+    let mut funder = MockAccount::wallet(funder_key, 0);
+    let mut funding = MockAccount::token_account(Pubkey::new_unique(), mint_key, funder_key, 1_000);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let mut token = MockAccount::program(spl_token::ID);
+    let mut ata = MockAccount::program(spl_associated_token_account::ID);
+    let (distributor, vault, mint, chunk, claimant, recipient, funder, funding) = (
+        distributor.info(false, true),
+        vault.info(false, true),
+        mint.info(false, false),
+        chunk.info(false, true),
+        claimant.info(true, false),
+        recipient.info(false, true),
+        funder.info(true, false),
+        funding.info(false, true),
+    );
+    let (payer, system, token, ata) = (
+        payer.info(true, true),
+        system.info(false, false),
+        token.info(false, false),
+        ata.info(false, false),
+    );
+    let accounts = DistributorAccounts {
+        distributor: &distributor,
+        vault: &vault,
+        mint: &mint,
+        token_program: &token,
+    };
+
+    let params = DistributorParams {
+        base,
+        authority: Pubkey::new_unique(),
+        root: airdrop.tree.root(),
+        expires_at: 100,
+    };
+    DISTRIBUTOR
+        .create(&accounts, &payer, &system, &ata, &params)
+        .unwrap();
+    assert_eq!(*distributor.owner, TEST_PROGRAM_ID);
+    assert_eq!(*vault.owner, spl_token::ID);
+    let state = DISTRIBUTOR.load(&distributor).unwrap();
+    assert_eq!((state.vault, state.mint), (vault_key, mint_key));
+
+    Transfers::transfer_token(
+        funding,
+        mint.clone(),
+        vault.clone(),
+        token.clone(),
+        funder,
+        425,
+        0,
+        &[],
+    )
+    .unwrap();
+    DISTRIBUTOR
+        .claims(&distributor_key)
+        .create_chunk(&chunk, &system, &payer, 0)
+        .unwrap();
+
+    let proof = airdrop.tree.proof(1).unwrap();
+    let args = ClaimArgs {
+        index: 1,
+        amount,
+        proof: &proof,
+    };
+    let forged = ClaimArgs {
+        amount: amount + 1,
+        ..args
+    };
+    assert!(matches!(
+        DISTRIBUTOR.claim(&accounts, &chunk, &claimant, &recipient, &forged),
+        Err(Errors::InvalidMerkleProof)
+    ));
+    DISTRIBUTOR
+        .claim(&accounts, &chunk, &claimant, &recipient, &args)
+        .unwrap();
+    assert!(matches!(
+        DISTRIBUTOR.claim(&accounts, &chunk, &claimant, &recipient, &args),
+        Err(Errors::AlreadyClaimed)
+    ));
+
+    let recipient = TokenAccountLoader::load(&recipient).unwrap();
+    assert_eq!(recipient.state().amount, amount);
+    assert_eq!(
+        TokenAccountLoader::load(&vault).unwrap().state().amount,
+        425 - amount
+    );
+    assert_eq!(
+        DISTRIBUTOR.load(&distributor).unwrap().total_claimed,
+        amount
+    );
+}
+
+#[test]
+fn claims_close_and_clawback_opens_at_expiry() {
+    install();
+    let airdrop = Airdrop::new(&[40, 60]);
+    let base = Pubkey::new_unique();
+    let (distributor_key, _) = DISTRIBUTOR.address(&base);
+    let mint_key = Pubkey::new_unique();
+    let vault_key = get_associated_token_address(&distributor_key, &mint_key);
+    let authority_key = Pubkey::new_unique();
+    let (claimant_key, amount) = airdrop.claimants[0];
+
+    let mut distributor = MockAccount::wallet(distributor_key, 0);
+    let mut vault = MockAccount::token_account(vault_key, mint_key, distributor_key, 100);
+    let mut mint = MockAccount::mint(mint_key, 100, 0);
+    let mut chunk = MockAccount::wallet(DISTRIBUTOR.claims(&distributor_key).chunk_address(0), 0);
+    let mut claimant = MockAccount::wallet(claimant_key, 0);
+    let mut authority = MockAccount::wallet(authority_key, 0);
+    let mut destination =
+        MockAccount::token_account(Pubkey::new_unique(), mint_key, authority_key, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let mut token = MockAccount::program(spl_token::ID);
+    let mut ata = MockAccount::program(spl_associated_token_account::ID);
+    let (distributor, vault, mint, chunk, claimant, destination) = (
+        distributor.info(false, true),
+        vault.info(false, true),
+        mint.info(false, false),
+        chunk.info(false, true),
+        claimant.info(true, false),
+        destination.info(false, true),
+    );
+    let (authority, payer, system, token, ata) = (
+        authority.info(true, false),
+        payer.info(true, true),
+        system.info(false, false),
+        token.info(false, false),
+        ata.info(false, false),
+    );
+    let accounts = DistributorAccounts {
+        distributor: &distributor,
+        vault: &vault,
+        mint: &mint,
+        token_program: &token,
+    };
+
+    // The stubbed clock is at unix timestamp 0, so the distributor is already expired.
+    let params = DistributorParams {
+        base,
+        authority: authority_key,
+        root: airdrop.tree.root(),
+        expires_at: 0,
+    };
+    DISTRIBUTOR
+        .create(&accounts, &payer, &system, &ata, &params)
+        .unwrap();
+    DISTRIBUTOR
+        .claims(&distributor_key)
+        .create_chunk(&chunk, &system, &payer, 0)
+        .unwrap();
+
+    let proof = airdrop.tree.proof(0).unwrap();
+    let args = ClaimArgs {
+        index: 0,
+        amount,
+        proof: &proof,
+    };
+    assert!(matches!(
+        DISTRIBUTOR.claim(&accounts, &chunk, &claimant, &destination, &args),
+        Err(Errors::DistributorExpired)
+    ));
+    assert!(matches!(
+        DISTRIBUTOR.clawback(&accounts, &claimant, &destination),
+        Err(Errors::OwnerMismatch)
+    ));
+    assert_eq!(
+        DISTRIBUTOR
+            .clawback(&accounts, &authority, &destination)
+            .unwrap(),
+        100
+    );
+    assert_eq!(
+        TokenAccountLoader::load(&destination)
+            .unwrap()
+            .state()
+            .amount,
+        100
+    );
+}
+
+#[test]
+fn clawback_waits_for_expiry() {
+    install();
+    let base = Pubkey::new_unique();
+    let (distributor_key, _) = DISTRIBUTOR.address(&base);
+    let mint_key = Pubkey::new_unique();
+    let vault_key = get_associated_token_address(&distributor_key, &mint_key);
+    let authority_key = Pubkey::new_unique();
+
+    let mut distributor = MockAccount::wallet(distributor_key, 0);
+    let mut vault = MockAccount::wallet(vault_key, 0);
+    let mut mint = MockAccount::mint(mint_key, 0, 0);
+    let mut authority = MockAccount::wallet(authority_key, 0);
+    let mut destination =
+        MockAccount::token_account(Pubkey::new_unique(), mint_key, authority_key, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let mut token = MockAccount::program(spl_token::ID);
+    let mut ata = MockAccount::program(spl_associated_token_account::ID);
+    let (distributor, vault, mint, authority, destination) = (
+        distributor.info(false, true),
+        vault.info(false, true),
+        mint.info(false, false),
+        authority.info(true, false),
+        destination.info(false, true),
+    );
+    let (payer, system, token, ata) = (
+        payer.info(true, true),
+        system.info(false, false),
+        token.info(false, false),
+        ata.info(false, false),
+    );
+    let accounts = DistributorAccounts {
+        distributor: &distributor,
+        vault: &vault,
+        mint: &mint,
+        token_program: &token,
+    };
+
+    let params = DistributorParams {
+        base,
+        authority: authority_key,
+        root: [0; 32],
+        expires_at: 100,
+    };
+    DISTRIBUTOR
+        .create(&accounts, &payer, &system, &ata, &params)
+        .unwrap();
+    assert!(matches!(
+        DISTRIBUTOR.clawback(&accounts, &authority, &destination),
+        Err(Errors::DistributorNotExpired)
+    ));
+}
@@ -0,0 +1,118 @@
+use borsh::BorshDeserialize;
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::fixed::{BoundedVec, FixedBytes, FixedString};
+use solana_program::program_error::ProgramError;
+use solana_program::program_pack::Pack;
+
+#[test]
+fn encodings_share_one_layout() {
+    let value: FixedString<8> = "héllo".parse().unwrap();
+
+    let mut packed = [0u8; FixedString::<8>::LEN];
+    value.pack_into_slice(&mut packed);
+    let borsh = borsh::to_vec(&value).unwrap();
+
+    assert_eq!(packed.len(), 12);
+    assert_eq!(&packed[..4], &6u32.to_le_bytes());
+    assert_eq!(borsh, packed);
+    assert_eq!(bytemuck::bytes_of(&value), packed);
+    assert_eq!(FixedString::<8>::unpack_from_slice(&packed).unwrap(), value);
+    assert_eq!(FixedString::<8>::try_from_slice(&borsh).unwrap(), value);
+    assert_eq!(
+        bytemuck::from_bytes::<FixedString<8>>(&packed)
+            .as_str()
+            .unwrap(),
+        "héllo"
+    );
+}
+
+#[test]
+fn rejects_values_over_capacity() {
+    assert!(matches!(
+        FixedString::<4>::try_from("hello"),
+        Err(Errors::FixedCapacityExceeded)
+    ));
+    assert!(matches!(
+        FixedBytes::<2>::from_slice(&[1, 2, 3]),
+        Err(Errors::FixedCapacityExceeded)
+    ));
+    assert_eq!(
+        FixedBytes::<4>::from_slice(&[0, 1])
+            .unwrap()
+            .as_bytes()
+            .unwrap(),
+        &[0, 1]
+    );
+}
+
+#[test]
+fn rejects_corrupted_data() {
+    let mut too_long = [0u8; FixedBytes::<4>::LEN];
+    too_long[..4].copy_from_slice(&5u32.to_le_bytes());
+    let mut not_utf8 = [0u8; FixedString::<4>::LEN];
+    not_utf8[..4].copy_from_slice(&1u32.to_le_bytes());
+    not_utf8[4] = 0xff;
+
+    assert_eq!(
+        FixedBytes::<4>::unpack_from_slice(&too_long).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert!(FixedBytes::<4>::try_from_slice(&too_long).is_err());
+    assert!(matches!(
+        bytemuck::from_bytes::<FixedBytes<4>>(&too_long).as_bytes(),
+        Err(Errors::FixedCapacityExceeded)
+    ));
+    assert_eq!(
+        FixedString::<4>::unpack_from_slice(&not_utf8).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert!(FixedString::<4>::try_from_slice(&not_utf8).is_err());
+    assert!(matches!(
+        bytemuck::from_bytes::<FixedString<4>>(&not_utf8).as_str(),
+        Err(Errors::InvalidUtf8)
+    ));
+}
+
+#[test]
+fn rejects_non_zero_padding() {
+    let value: FixedString<4> = "ab".parse().unwrap();
+    let mut dirty = borsh::to_vec(&value).unwrap();
+    dirty[7] = b'c';
+
+    assert_eq!(
+        FixedBytes::<4>::unpack_from_slice(&dirty).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert!(FixedBytes::<4>::try_from_slice(&dirty).is_err());
+    assert_eq!(
+        FixedString::<4>::unpack_from_slice(&dirty).unwrap_err(),
+        ProgramError::InvalidAccountData
+    );
+    assert!(FixedString::<4>::try_from_slice(&dirty).is_err());
+    dirty[7] = 0;
+    assert_eq!(FixedString::<4>::try_from_slice(&dirty).unwrap(), value);
+}
+
+#[test]
+fn bounded_vec_enforces_capacity() {
+    let mut items = BoundedVec::<u16, 2>::new();
+    items.try_push(1).unwrap();
+    items.try_push(2).unwrap();
+
+    assert!(matches!(
+        items.try_push(3),
+        Err(Errors::FixedCapacityExceeded)
+    ));
+    assert_eq!(&*items, &[1, 2]);
+    assert_eq!(
+        borsh::to_vec(&items).unwrap(),
+        borsh::to_vec(&vec![1u16, 2]).unwrap()
+    );
+    assert!(
+        BoundedVec::<u16, 2>::try_from_slice(&borsh::to_vec(&vec![1u16, 2, 3]).unwrap()).is_err()
+    );
+    assert!(matches!(
+        BoundedVec::<u16, 2>::from_vec(vec![1, 2, 3]),
+        Err(Errors::FixedCapacityExceeded)
+    ));
+}
@@ -0,0 +1,150 @@
+use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication};
+use solana_helpers::errors::Errors;
+use solana_helpers::zk::groth16::{Groth16, Proof, VerifyingKey, SCALAR_FIELD_MODULUS};
+
+/// The G2 generator in the EIP-197 layout.
+const G2: [u8; 128] = hex32x4([
+    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+]);
+
+const fn hex32x4(words: [&str; 4]) -> [u8; 128] {
+    let mut out = [0u8; 128];
+    let mut i = 0;
+    while i < 128 {
+        let word = words[i / 32].as_bytes();
+        let j = (i % 32) * 2;
+        out[i] = nibble(word[j]) << 4 | nibble(word[j + 1]);
+        i += 1;
+    }
+    out
+}
+
+const fn nibble(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        _ => c - b'a' + 10,
+    }
+}
+
+fn scalar(value: u64) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[24..].copy_from_slice(&value.to_be_bytes());
+    out
+}
+
+/// `k` times the G1 generator.
+fn g1(k: u64) -> [u8; 64] {
+    let mut generator = [0u8; 64];
+    generator[31] = 1;
+    generator[63] = 2;
+    let point = alt_bn128_multiplication(&[generator.as_slice(), &scalar(k)].concat()).unwrap();
+    point.try_into().unwrap()
+}
+
+fn add(a: &[u8; 64], b: &[u8; 64]) -> [u8; 64] {
+    let sum = alt_bn128_addition(&[a.as_slice(), b].concat()).unwrap();
+    sum.try_into().unwrap()
+}
+
+/// With every G2 point set to the generator, the pairing check reduces to
+/// `A == alpha + vk_x + C` in G1, so valid proofs can be built from scalars alone.
+struct Fixture {
+    ic: [[u8; 64]; 3],
+    proof: Proof,
+    inputs: [[u8; 32]; 2],
+}
+
+impl Fixture {
+    fn new() -> Self {
+        let inputs = [scalar(2), scalar(9)];
+        // alpha = 3, ic = [5, 7, 11], C = 13: A = 3 + (5 + 2 * 7 + 9 * 11) + 13.
+        let proof = Proof {
+            a: g1(134),
+            b: G2,
+            c: g1(13),
+        };
+        Self {
+            ic: [g1(5), g1(7), g1(11)],
+            proof,
+            inputs,
+        }
+    }
+
+    fn vk(&self) -> VerifyingKey<'_> {
+        VerifyingKey {
+            alpha_g1: g1(3),
+            beta_g2: G2,
+            gamma_g2: G2,
+            delta_g2: G2,
+            ic: &self.ic,
+        }
+    }
+}
+
+#[test]
+fn verifies_valid_proofs() {
+    let fixture = Fixture::new();
+    let vk = fixture.vk();
+
+    assert_eq!(
+        Groth16::prepare_inputs(&vk, &fixture.inputs).unwrap(),
+        add(&g1(5), &g1(2 * 7 + 9 * 11))
+    );
+    Groth16::verify(&vk, &fixture.proof, &fixture.inputs).unwrap();
+
+    let negated = Proof {
+        a: Groth16::negate_g1(&fixture.proof.a).unwrap(),
+        ..fixture.proof
+    };
+    Groth16::verify_negated(&vk, &negated, &fixture.inputs).unwrap();
+    assert!(matches!(
+        Groth16::verify_negated(&vk, &fixture.proof, &fixture.inputs),
+        Err(Errors::ProofVerificationFailed)
+    ));
+}
+
+#[test]
+fn rejects_wrong_inputs() {
+    let fixture = Fixture::new();
+    let vk = fixture.vk();
+
+    assert!(matches!(
+        Groth16::verify(&vk, &fixture.proof, &[scalar(2), scalar(10)]),
+        Err(Errors::ProofVerificationFailed)
+    ));
+    assert!(matches!(
+        Groth16::verify(&vk, &fixture.proof, &[scalar(2)]),
+        Err(Errors::PublicInputCountMismatch)
+    ));
+    assert!(matches!(
+        Groth16::verify(&vk, &fixture.proof, &[scalar(2), SCALAR_FIELD_MODULUS]),
+        Err(Errors::PublicInputOutOfField)
+    ));
+}
+
+#[test]
+fn rejects_invalid_points() {
+    let fixture = Fixture::new();
+    let vk = fixture.vk();
+
+    let off_curve = Proof {
+        c: [1; 64],
+        ..fixture.proof
+    };
+    assert!(matches!(
+        Groth16::verify(&vk, &off_curve, &fixture.inputs),
+        Err(Errors::InvalidCurvePoint)
+    ));
+    assert!(matches!(
+        Groth16::negate_g1(&[0xff; 64]),
+        Err(Errors::InvalidCurvePoint)
+    ));
+    assert_eq!(Groth16::negate_g1(&[0; 64]).unwrap(), [0; 64]);
+    assert_eq!(
+        Groth16::negate_g1(&Groth16::negate_g1(&g1(7)).unwrap()).unwrap(),
+        g1(7)
+    );
+}
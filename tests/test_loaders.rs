@@ -0,0 +1,175 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::loaders::{MintLoader, TokenAccountLoader};
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::{Account, AccountState, Mint};
+use stubs::mock_account::MockAccount;
+
+fn token_state(mint: Pubkey, owner: Pubkey, amount: u64) -> Account {
+    Account {
+        mint,
+        owner,
+        amount,
+        state: AccountState::Initialized,
+        ..Account::default()
+    }
+}
+
+#[test]
+fn token_account_assertions() {
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut account = MockAccount::token_account(Pubkey::new_unique(), mint, owner, 50);
+    let info = account.info(false, false);
+
+    let loader = TokenAccountLoader::load(&info).unwrap();
+    loader
+        .assert_mint(&mint)
+        .and_then(|l| l.assert_owner(&owner))
+        .and_then(|l| l.assert_min_amount(50))
+        .and_then(TokenAccountLoader::assert_not_frozen)
+        .and_then(TokenAccountLoader::assert_no_delegate)
+        .unwrap();
+
+    assert!(matches!(
+        loader.assert_mint(&owner),
+        Err(Errors::MintMismatch)
+    ));
+    assert!(matches!(
+        loader.assert_owner(&mint),
+        Err(Errors::OwnerMismatch)
+    ));
+    assert!(matches!(
+        loader.assert_min_amount(51),
+        Err(Errors::InsufficientFunds)
+    ));
+}
+
+#[test]
+fn token_account_frozen_and_delegated() {
+    let state = Account {
+        state: AccountState::Frozen,
+        delegate: COption::Some(Pubkey::new_unique()),
+        ..token_state(Pubkey::new_unique(), Pubkey::new_unique(), 1)
+    };
+    let mut account = MockAccount::packed(Pubkey::new_unique(), spl_token::ID, state);
+    let info = account.info(false, false);
+
+    let loader = TokenAccountLoader::load(&info).unwrap();
+    assert!(matches!(
+        loader.assert_not_frozen(),
+        Err(Errors::AccountFrozen)
+    ));
+    assert!(matches!(
+        loader.assert_no_delegate(),
+        Err(Errors::UnexpectedDelegate)
+    ));
+}
+
+#[test]
+fn token_account_rejects_wrong_program_and_data() {
+    let state = token_state(Pubkey::new_unique(), Pubkey::new_unique(), 1);
+    let mut foreign = MockAccount::packed(Pubkey::new_unique(), Pubkey::new_unique(), state);
+    let mut mint = MockAccount::mint(Pubkey::new_unique(), 0, 6);
+    let (foreign, mint) = (foreign.info(false, false), mint.info(false, false));
+
+    assert!(matches!(
+        TokenAccountLoader::load(&foreign),
+        Err(Errors::InvalidAccountOwner)
+    ));
+    assert!(matches!(
+        TokenAccountLoader::load(&mint),
+        Err(Errors::InvalidAccountData)
+    ));
+}
+
+#[test]
+fn token_2022_account_with_extensions() {
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = vec![0u8; Account::LEN + 1 + 8];
+    Account::pack(token_state(mint, owner, 7), &mut data[..Account::LEN]).unwrap();
+    data[Account::LEN] = 2;
+    let mut account = MockAccount::new(Pubkey::new_unique(), spl_token_2022::ID, 1, &data);
+    let info = account.info(false, false);
+
+    let loader = TokenAccountLoader::load(&info).unwrap();
+    assert_eq!(loader.state().amount, 7);
+    loader.assert_mint(&mint).unwrap();
+
+    data[Account::LEN] = 1;
+    let mut mismatched = MockAccount::new(Pubkey::new_unique(), spl_token_2022::ID, 1, &data);
+    let mismatched = mismatched.info(false, false);
+    assert!(matches!(
+        TokenAccountLoader::load(&mismatched),
+        Err(Errors::InvalidAccountData)
+    ));
+}
+
+#[test]
+fn token_2022_multisig_is_not_a_token_account() {
+    let (mint, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let mut data = vec![0u8; spl_token::state::Multisig::LEN];
+    Account::pack(token_state(mint, owner, u64::MAX), &mut data[..Account::LEN]).unwrap();
+    data[Account::LEN] = 2;
+    let mut multisig = MockAccount::new(Pubkey::new_unique(), spl_token_2022::ID, 1, &data);
+    let multisig = multisig.info(false, false);
+
+    assert!(matches!(
+        TokenAccountLoader::load(&multisig),
+        Err(Errors::InvalidAccountData)
+    ));
+}
+
+#[test]
+fn token_2022_mint_rejects_dirty_padding() {
+    let state = Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..Mint::default()
+    };
+    let mut data = vec![0u8; Account::LEN + 1 + 8];
+    Mint::pack(state, &mut data[..Mint::LEN]).unwrap();
+    data[Account::LEN] = 1;
+    let mut mint = MockAccount::new(Pubkey::new_unique(), spl_token_2022::ID, 1, &data);
+    let info = mint.info(false, false);
+    assert_eq!(MintLoader::load(&info).unwrap().state().decimals, 6);
+
+    data[Mint::LEN] = 1;
+    let mut dirty = MockAccount::new(Pubkey::new_unique(), spl_token_2022::ID, 1, &data);
+    let dirty = dirty.info(false, false);
+    assert!(matches!(
+        MintLoader::load(&dirty),
+        Err(Errors::InvalidAccountData)
+    ));
+}
+
+#[test]
+fn mint_assertions() {
+    let authority = Pubkey::new_unique();
+    let state = Mint {
+        mint_authority: COption::Some(authority),
+        supply: 10,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    };
+    let mut mint = MockAccount::packed(Pubkey::new_unique(), spl_token::ID, state);
+    let info = mint.info(false, false);
+
+    let loader = MintLoader::load(&info).unwrap();
+    loader
+        .assert_mint_authority(Some(&authority))
+        .and_then(|l| l.assert_decimals(9))
+        .unwrap();
+
+    assert!(matches!(
+        loader.assert_mint_authority(None),
+        Err(Errors::MintAuthorityMismatch)
+    ));
+    assert!(matches!(
+        loader.assert_decimals(6),
+        Err(Errors::DecimalsMismatch)
+    ));
+}
@@ -0,0 +1,123 @@
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::merkle::{Merkle, MerkleHash, MerkleTree, ProofOrder, EMPTY_NODE};
+
+const SCHEMES: [Merkle; 4] = [
+    Merkle::new(MerkleHash::Sha256, ProofOrder::SortedPair),
+    Merkle::new(MerkleHash::Sha256, ProofOrder::Indexed),
+    Merkle::new(MerkleHash::Keccak256, ProofOrder::SortedPair),
+    Merkle::new(MerkleHash::Keccak256, ProofOrder::Indexed),
+];
+
+fn leaf_parts(index: u64) -> [Vec<u8>; 2] {
+    [b"claimant".to_vec(), index.to_le_bytes().to_vec()]
+}
+
+fn verify(
+    merkle: &Merkle,
+    leaf: u64,
+    index: u64,
+    proof: &[[u8; 32]],
+    root: &[u8; 32],
+) -> Result<(), Errors> {
+    let parts = leaf_parts(leaf);
+    merkle.verify(&[&parts[0], &parts[1]], index, proof, root)
+}
+
+fn leaves(merkle: &Merkle, count: u64) -> Vec<[u8; 32]> {
+    (0..count)
+        .map(|index| {
+            let parts = leaf_parts(index);
+            merkle.hash_leaf_parts(&[&parts[0], &parts[1]])
+        })
+        .collect()
+}
+
+#[test]
+fn tree_proofs_verify_for_every_leaf() {
+    for merkle in SCHEMES {
+        for count in 1..=7 {
+            let leaves = leaves(&merkle, count);
+            let tree = MerkleTree::new(merkle, leaves.clone());
+            for index in 0..leaves.len() {
+                let proof = tree.proof(index).unwrap();
+                verify(&merkle, index as u64, index as u64, &proof, &tree.root()).unwrap();
+            }
+            assert!(tree.proof(leaves.len()).is_none());
+        }
+    }
+}
+
+#[test]
+fn rejects_tampered_proofs() {
+    for merkle in SCHEMES {
+        let leaves = leaves(&merkle, 5);
+        let tree = MerkleTree::new(merkle, leaves.clone());
+        let mut proof = tree.proof(2).unwrap();
+
+        assert!(matches!(
+            verify(&merkle, 3, 2, &proof, &tree.root()),
+            Err(Errors::InvalidMerkleProof)
+        ));
+        proof[0][0] ^= 1;
+        assert!(matches!(
+            verify(&merkle, 2, 2, &proof, &tree.root()),
+            Err(Errors::InvalidMerkleProof)
+        ));
+    }
+}
+
+#[test]
+fn indexed_proofs_bind_the_index() {
+    let sorted = SCHEMES[0];
+    let indexed = SCHEMES[1];
+    let sorted_tree = MerkleTree::new(sorted, leaves(&sorted, 4));
+    let indexed_tree = MerkleTree::new(indexed, leaves(&indexed, 4));
+
+    let proof = sorted_tree.proof(1).unwrap();
+    verify(&sorted, 1, 3, &proof, &sorted_tree.root()).unwrap();
+
+    let proof = indexed_tree.proof(1).unwrap();
+    verify(&indexed, 1, 1, &proof, &indexed_tree.root()).unwrap();
+    assert!(matches!(
+        verify(&indexed, 1, 3, &proof, &indexed_tree.root()),
+        Err(Errors::InvalidMerkleProof)
+    ));
+    assert!(matches!(
+        verify(&indexed, 1, 5, &proof, &indexed_tree.root()),
+        Err(Errors::InvalidMerkleProof)
+    ));
+}
+
+#[test]
+fn inner_nodes_are_not_leaves() {
+    for merkle in SCHEMES {
+        let leaves = leaves(&merkle, 4);
+        let tree = MerkleTree::new(merkle, leaves.clone());
+        let node = merkle.hash_nodes(&leaves[0], &leaves[1]);
+        let proof = tree.proof(0).unwrap();
+
+        assert!(matches!(
+            merkle.verify(&[&node], 0, &proof[1..], &tree.root()),
+            Err(Errors::InvalidMerkleProof)
+        ));
+        assert!(matches!(
+            merkle.verify(&[&leaves[0], &leaves[1]], 0, &proof[1..], &tree.root()),
+            Err(Errors::InvalidMerkleProof)
+        ));
+    }
+}
+
+#[test]
+fn odd_levels_pair_with_the_empty_node() {
+    let merkle = SCHEMES[1];
+    let leaves = leaves(&merkle, 3);
+    let tree = MerkleTree::new(merkle, leaves.clone());
+    let expected = merkle.hash_nodes(
+        &merkle.hash_nodes(&leaves[0], &leaves[1]),
+        &merkle.hash_nodes(&leaves[2], &EMPTY_NODE),
+    );
+
+    assert_eq!(tree.root(), expected);
+    assert_eq!(tree.proof(2).unwrap()[0], EMPTY_NODE);
+    assert_eq!(MerkleTree::new(merkle, Vec::new()).root(), EMPTY_NODE);
+}
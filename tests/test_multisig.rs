@@ -0,0 +1,209 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::generic::Generic;
+use solana_helpers::helpers::loaders::TokenAccountLoader;
+use solana_helpers::helpers::pda_signer::PdaSigner;
+use solana_helpers::helpers::transfers::Transfers;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use spl_token::state::{Account, Mint, Multisig};
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+struct Fixture {
+    members: [Pubkey; 3],
+    multisig: MockAccount,
+    mint: MockAccount,
+    from: MockAccount,
+    to: MockAccount,
+    token_program: MockAccount,
+}
+
+fn fixture() -> Fixture {
+    fixture_with_members([
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ])
+}
+
+fn fixture_with_members(members: [Pubkey; 3]) -> Fixture {
+    let mut signers = [Pubkey::default(); spl_token::instruction::MAX_SIGNERS];
+    signers[..3].copy_from_slice(&members);
+    let state = Multisig {
+        m: 2,
+        n: 3,
+        is_initialized: true,
+        signers,
+    };
+    let multisig_key = Pubkey::new_unique();
+    let mint_key = Pubkey::new_unique();
+    Fixture {
+        members,
+        multisig: MockAccount::packed(multisig_key, spl_token::ID, state),
+        mint: MockAccount::mint(mint_key, 100, 0),
+        from: MockAccount::token_account(Pubkey::new_unique(), mint_key, multisig_key, 100),
+        to: MockAccount::token_account(Pubkey::new_unique(), mint_key, multisig_key, 0),
+        token_program: MockAccount::program(spl_token::ID),
+    }
+}
+
+#[test]
+fn transfer_with_threshold_signers() {
+    install();
+    let mut f = fixture();
+    let mut first = MockAccount::wallet(f.members[0], 0);
+    let mut third = MockAccount::wallet(f.members[2], 0);
+
+    Transfers::transfer_token(
+        f.from.info(false, true),
+        f.mint.info(false, false),
+        f.to.info(false, true),
+        f.token_program.info(false, false),
+        f.multisig.info(false, false),
+        30,
+        0,
+        &[first.info(true, false), third.info(true, false)],
+    )
+    .unwrap();
+
+    assert_eq!(Account::unpack(f.to.data()).unwrap().amount, 30);
+}
+
+#[test]
+fn transfer_signed_by_pda_members() {
+    install();
+    let first = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"member", &[0]]);
+    let second = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"member", &[1]]);
+    let mut f = fixture_with_members([*first.address(), *second.address(), Pubkey::new_unique()]);
+    let mut first_member = MockAccount::wallet(*first.address(), 0);
+    let mut second_member = MockAccount::wallet(*second.address(), 0);
+    let (from, mint, to, token_program, multisig) = (
+        f.from.info(false, true),
+        f.mint.info(false, false),
+        f.to.info(false, true),
+        f.token_program.info(false, false),
+        f.multisig.info(false, false),
+    );
+    let members = [
+        first_member.info(false, false),
+        second_member.info(false, false),
+    ];
+
+    let err = Transfers::transfer_token_from_pda(
+        from.clone(),
+        mint.clone(),
+        to.clone(),
+        token_program.clone(),
+        multisig.clone(),
+        30,
+        0,
+        &members,
+        &[&first],
+    )
+    .unwrap_err();
+    assert!(matches!(err, Errors::ProgramError(_)));
+
+    Transfers::transfer_token_from_pda(
+        from,
+        mint,
+        to,
+        token_program,
+        multisig,
+        30,
+        0,
+        &members,
+        &[&first, &second],
+    )
+    .unwrap();
+
+    assert_eq!(Account::unpack(f.to.data()).unwrap().amount, 30);
+}
+
+#[test]
+fn burn_with_threshold_signers() {
+    install();
+    let mut f = fixture();
+    let mut first = MockAccount::wallet(f.members[0], 0);
+    let mut second = MockAccount::wallet(f.members[1], 0);
+
+    Generic::burn_tokens(
+        f.token_program.info(false, false),
+        f.from.info(false, true),
+        f.mint.info(false, true),
+        f.multisig.info(false, false),
+        10,
+        &[first.info(true, false), second.info(true, false)],
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(Account::unpack(f.from.data()).unwrap().amount, 90);
+    assert_eq!(Mint::unpack(f.mint.data()).unwrap().supply, 90);
+}
+
+#[test]
+fn rejects_below_threshold_and_non_members() {
+    install();
+    let mut f = fixture();
+    let mut first = MockAccount::wallet(f.members[0], 0);
+    let mut outsider = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let (from, mint, to, token_program, multisig) = (
+        f.from.info(false, true),
+        f.mint.info(false, false),
+        f.to.info(false, true),
+        f.token_program.info(false, false),
+        f.multisig.info(false, false),
+    );
+    let (first, outsider) = (first.info(true, false), outsider.info(true, false));
+
+    let err = Transfers::transfer_token(
+        from.clone(),
+        mint.clone(),
+        to.clone(),
+        token_program.clone(),
+        multisig.clone(),
+        30,
+        0,
+        &[first.clone(), first.clone()],
+    )
+    .unwrap_err();
+    assert!(matches!(err, Errors::NotEnoughSigners));
+
+    let err = Transfers::transfer_token(
+        from.clone(),
+        mint,
+        to,
+        token_program,
+        multisig,
+        30,
+        0,
+        &[first, outsider],
+    )
+    .unwrap_err();
+    assert!(matches!(err, Errors::InvalidMultisigSigner));
+    assert_eq!(TokenAccountLoader::load(&from).unwrap().state().amount, 100);
+}
+
+#[test]
+fn signers_require_a_multisig_authority() {
+    install();
+    let mut f = fixture();
+    let mut wallet = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut first = MockAccount::wallet(f.members[0], 0);
+
+    let err = Transfers::transfer_token(
+        f.from.info(false, true),
+        f.mint.info(false, false),
+        f.to.info(false, true),
+        f.token_program.info(false, false),
+        wallet.info(true, false),
+        30,
+        0,
+        &[first.info(true, false)],
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::InvalidAccountOwner));
+}
@@ -0,0 +1,57 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::pda_signer::PdaSigner;
+use solana_helpers::helpers::transfers::Transfers;
+use solana_program::pubkey::Pubkey;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+#[test]
+fn derive_matches_find_program_address() {
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"vault", &[1, 2]]);
+    let (pda, bump) = Pubkey::find_program_address(&[b"vault", &[1, 2]], &TEST_PROGRAM_ID);
+
+    assert_eq!(*signer.address(), pda);
+    assert_eq!(signer.bump(), bump);
+    assert_eq!(signer.signer_seeds(), vec![&b"vault"[..], &[1, 2], &[bump]]);
+    assert_eq!(
+        PdaSigner::with_bump(&TEST_PROGRAM_ID, &[b"vault", &[1, 2]], bump).unwrap(),
+        signer
+    );
+}
+
+#[test]
+fn with_bump_rejects_on_curve_address() {
+    let bump = (0..=u8::MAX)
+        .find(|bump| {
+            Pubkey::create_program_address(&[b"vault", &[*bump]], &TEST_PROGRAM_ID).is_err()
+        })
+        .unwrap();
+
+    let err = PdaSigner::with_bump(&TEST_PROGRAM_ID, &[b"vault"], bump).unwrap_err();
+
+    assert!(matches!(err, Errors::InvalidSeeds));
+}
+
+#[test]
+fn transfer_sol_signed_rejects_other_account() {
+    install();
+    let signer = PdaSigner::derive(&TEST_PROGRAM_ID, &[b"vault"]);
+    let mut from = MockAccount::wallet(Pubkey::new_unique(), 1_000);
+    let mut to = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut system = MockAccount::program(system_program::ID);
+
+    let err = Transfers::transfer_sol_signed(
+        from.info(false, true),
+        to.info(false, true),
+        system.info(false, false),
+        500,
+        &signer,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, Errors::WrongPdaAddress));
+    assert_eq!(from.lamports(), 1_000);
+}
@@ -0,0 +1,91 @@
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::conversions::Conversions;
+use solana_helpers::zk::groth16::SCALAR_FIELD_MODULUS;
+use solana_helpers::zk::poseidon::{Endianness, Poseidon};
+
+fn element(value: u64) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[24..].copy_from_slice(&value.to_be_bytes());
+    out
+}
+
+fn little_endian(bytes: &[u8; 32]) -> [u8; 32] {
+    Conversions::vec_to_array(&Conversions::change_endianness(bytes))
+}
+
+#[test]
+fn matches_circomlib() {
+    // circomlibjs `poseidon([1, 2])`.
+    let expected = [
+        0x11, 0x5c, 0xc0, 0xf5, 0xe7, 0xd6, 0x90, 0x41, 0x3d, 0xf6, 0x4c, 0x6b, 0x96, 0x62, 0xe9,
+        0xcf, 0x2a, 0x36, 0x17, 0xf2, 0x74, 0x32, 0x45, 0x51, 0x9e, 0x19, 0x60, 0x7a, 0x44, 0x17,
+        0x18, 0x9a,
+    ];
+    let inputs = [element(1), element(2)];
+
+    assert_eq!(
+        Poseidon::hash(&inputs, Endianness::BigEndian).unwrap(),
+        expected
+    );
+    assert_eq!(
+        Poseidon::hash(
+            &inputs.map(|input| little_endian(&input)),
+            Endianness::LittleEndian
+        )
+        .unwrap(),
+        little_endian(&expected)
+    );
+}
+
+#[test]
+fn rejects_invalid_inputs() {
+    assert!(matches!(
+        Poseidon::hash(&[], Endianness::BigEndian),
+        Err(Errors::InvalidPoseidonInput)
+    ));
+    assert!(matches!(
+        Poseidon::hash(&[element(1); 13], Endianness::BigEndian),
+        Err(Errors::InvalidPoseidonInput)
+    ));
+    assert!(matches!(
+        Poseidon::hash(&[SCALAR_FIELD_MODULUS], Endianness::BigEndian),
+        Err(Errors::FieldElementOutOfRange)
+    ));
+    assert!(Poseidon::is_field_element(
+        &little_endian(&element(5)),
+        Endianness::LittleEndian
+    ));
+    assert!(!Poseidon::is_field_element(
+        &little_endian(&SCALAR_FIELD_MODULUS),
+        Endianness::LittleEndian
+    ));
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn converts_between_bigint_and_field_elements() {
+    use num_bigint::BigInt;
+
+    let value = BigInt::from(0x0102_0304u32);
+    let big_endian = Poseidon::field_element_from_bigint(&value, Endianness::BigEndian).unwrap();
+    let little = Poseidon::field_element_from_bigint(&value, Endianness::LittleEndian).unwrap();
+
+    assert_eq!(big_endian, element(0x0102_0304));
+    assert_eq!(little, little_endian(&big_endian));
+    assert_eq!(
+        Poseidon::field_element_to_bigint(&little, Endianness::LittleEndian),
+        value
+    );
+    assert_eq!(
+        Poseidon::field_element_to_bigint(&big_endian, Endianness::BigEndian),
+        value
+    );
+
+    let modulus = Poseidon::field_element_to_bigint(&SCALAR_FIELD_MODULUS, Endianness::BigEndian);
+    for out_of_range in [modulus, BigInt::from(-1)] {
+        assert!(matches!(
+            Poseidon::field_element_from_bigint(&out_of_range, Endianness::BigEndian),
+            Err(Errors::FieldElementOutOfRange)
+        ));
+    }
+}
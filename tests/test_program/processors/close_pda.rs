@@ -1,5 +1,6 @@
 use crate::test_program::state::pda::PDA;
 use solana_helpers::helpers::closers::Closers;
+use solana_helpers::helpers::seeds::SeedValue;
 use solana_program::account_info::{AccountInfo, next_account_info};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_pack::Pack;
@@ -23,14 +24,16 @@ pub fn close_pda(
     assert!(solana_program::system_program::check_id(system_program.key));
     assert!(sysvar::rent::check_id(rent_program.key));
 
-    let (pda_key, bump_seed) = Pubkey::find_program_address(&[uuid.as_bytes()], program_id);
+    let (pda_key, bump_seed) = PDA::SEEDS
+        .find_address(program_id, &[SeedValue::Bytes(uuid.as_bytes())])
+        .unwrap();
     assert_eq!(*pda.key, pda_key);
     assert_eq!(bump, bump_seed);
 
     let pda_account = PDA::unpack_unchecked(&pda.try_borrow_data()?)?;
 
     assert_eq!(pda_account.bump, bump);
-    assert_eq!(pda_account.uuid, uuid);
+    assert_eq!(pda_account.uuid.as_str().unwrap(), uuid);
     Closers::close_pda(&mut pda.clone(), &mut signer.clone()).unwrap();
     Ok(())
 }
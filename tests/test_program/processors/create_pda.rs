@@ -1,6 +1,8 @@
 use crate::test_program::state::pda::PDA;
+use solana_helpers::helpers::seeds::SeedValue;
 use solana_program::account_info::{AccountInfo, next_account_info};
 use solana_program::entrypoint::ProgramResult;
+use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 use solana_program::sysvar;
@@ -22,13 +24,17 @@ pub fn create_pda(
     assert!(solana_program::system_program::check_id(system_program.key));
     assert!(sysvar::rent::check_id(rent_program.key));
 
-    let (pda_key, bump_seed) = Pubkey::find_program_address(&[uuid.as_bytes()], program_id);
+    let (pda_key, bump_seed) = PDA::SEEDS
+        .find_address(program_id, &[SeedValue::Bytes(uuid.as_bytes())])
+        .unwrap();
     assert_eq!(*pda.key, pda_key);
     assert_eq!(bump, bump_seed);
 
     let mut pda_account = PDA::unpack_unchecked(&pda.try_borrow_data()?)?;
     pda_account.bump = bump;
-    pda_account.uuid = uuid;
+    pda_account.uuid = uuid
+        .parse()
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
 
     PDA::pack(pda_account, &mut pda.try_borrow_mut_data()?)?;
 
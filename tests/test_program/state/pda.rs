@@ -1,39 +1,42 @@
 use arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs};
+use solana_helpers::helpers::fixed::FixedString;
+use solana_helpers::helpers::seeds::{SeedPart, SeedSchema};
+use solana_helpers::helpers::space::AccountSpace;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::{Pack, Sealed};
 
+#[derive(AccountSpace)]
+#[allow(clippy::upper_case_acronyms)]
 pub struct PDA {
     pub bump: u8,
-    pub uuid: String,
+    pub uuid: FixedString<16>,
 }
 
 impl PDA {
-    const SIZE: usize =
-    1 + // bump
-    16  // String
-    ;
+    /// Seeds of a PDA account: its 16-byte uuid.
+    pub const SEEDS: SeedSchema<1> = SeedSchema::new([SeedPart::Bytes(16)]);
 }
 
 impl Sealed for PDA {}
 
 impl Pack for PDA {
-    const LEN: usize = PDA::SIZE;
+    const LEN: usize = PDA::MAX_SPACE;
 
     fn pack_into_slice(&self, dst: &mut [u8]) {
         let dst = array_mut_ref![dst, 0, PDA::LEN];
-        let (bump_dst, uuid_dst) = mut_array_refs![dst, 1, 16];
+        let (bump_dst, uuid_dst) = mut_array_refs![dst, 1, FixedString::<16>::LEN];
 
         bump_dst[0] = self.bump;
-        uuid_dst.copy_from_slice(self.uuid.as_bytes());
+        self.uuid.pack_into_slice(uuid_dst);
     }
 
     fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
         let src = array_ref![src, 0, PDA::LEN];
-        let (bump, uuid) = array_refs![src, 1, 16];
+        let (bump, uuid) = array_refs![src, 1, FixedString::<16>::LEN];
 
         Ok(PDA {
             bump: bump[0],
-            uuid: String::from_utf8(uuid.to_vec()).unwrap(),
+            uuid: FixedString::unpack_from_slice(uuid)?,
         })
     }
 }
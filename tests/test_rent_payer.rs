@@ -0,0 +1,94 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::closers::Closers;
+use solana_helpers::helpers::generic::Generic;
+use solana_helpers::helpers::rent_payer::RentPayer;
+use solana_helpers::helpers::versioned::{AccountHeader, AccountSchema};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+const TICKET: AccountSchema = AccountSchema::new("Ticket", &[]);
+
+#[test]
+fn refunds_only_the_recorded_rent_payer() {
+    install();
+    let seeds: &[&[u8]] = &[b"payer-recorded"];
+    let (pda, _) = Pubkey::find_program_address(seeds, &TEST_PROGRAM_ID);
+    let space = AccountSchema::space(10).unwrap();
+    let mut target = MockAccount::wallet(pda, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut stranger = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut system = MockAccount::program(system_program::ID);
+    let (mut target, mut payer, mut stranger) = (
+        target.info(false, true),
+        payer.info(true, true),
+        stranger.info(false, true),
+    );
+
+    Generic::create_pda_account_with_rent_payer(
+        &target,
+        &system.info(false, false),
+        &payer,
+        space,
+        &TEST_PROGRAM_ID,
+        seeds,
+    )
+    .unwrap();
+    TICKET.initialize(&target, &[7; 10]).unwrap();
+    assert_eq!(target.lamports(), Rent::default().minimum_balance(space));
+    assert_eq!(
+        RentPayer::read(&target, &TEST_PROGRAM_ID).unwrap(),
+        *payer.key
+    );
+    let header = AccountHeader::read(&target.data.borrow()).unwrap();
+    assert_eq!(header.discriminator, TICKET.discriminator());
+    assert_eq!(header.rent_payer, Some(*payer.key));
+
+    let err =
+        Closers::close_pda_to_rent_payer(&mut target, &mut stranger, &TEST_PROGRAM_ID).unwrap_err();
+    assert!(matches!(err, Errors::RentPayerMismatch));
+    assert_eq!(stranger.lamports(), 0);
+
+    Closers::close_pda_to_rent_payer(&mut target, &mut payer, &TEST_PROGRAM_ID).unwrap();
+    assert_eq!(target.lamports(), 0);
+    assert_eq!(payer.lamports(), 10_000_000_000);
+}
+
+#[test]
+fn header_must_be_marked_and_owned_by_the_program() {
+    let payer = Pubkey::new_unique();
+    let mut unmarked = MockAccount::new(
+        Pubkey::new_unique(),
+        TEST_PROGRAM_ID,
+        1,
+        &[&[0; 16][..], payer.as_ref()].concat(),
+    );
+    let mut foreign = MockAccount::new(Pubkey::new_unique(), Pubkey::new_unique(), 1, &[0; 48]);
+    let mut short = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, 1, &[0; 8]);
+    let (unmarked, foreign, short) = (
+        unmarked.info(false, true),
+        foreign.info(false, true),
+        short.info(false, true),
+    );
+
+    assert!(matches!(
+        RentPayer::assert_payer(&unmarked, &TEST_PROGRAM_ID, &payer),
+        Err(Errors::RentPayerNotRecorded)
+    ));
+    RentPayer::write(&unmarked, &TEST_PROGRAM_ID, &payer).unwrap();
+    RentPayer::assert_payer(&unmarked, &TEST_PROGRAM_ID, &payer).unwrap();
+
+    RentPayer::write(&foreign, foreign.owner, &payer).unwrap();
+    assert!(matches!(
+        RentPayer::read(&foreign, &TEST_PROGRAM_ID),
+        Err(Errors::InvalidAccountOwner)
+    ));
+    assert!(matches!(
+        RentPayer::write(&short, &TEST_PROGRAM_ID, &payer),
+        Err(Errors::InvalidAccountData)
+    ));
+}
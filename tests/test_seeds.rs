@@ -0,0 +1,109 @@
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::seeds::{HashedSeed, SeedPart, SeedSchema, SeedValue};
+use solana_program::pubkey::Pubkey;
+
+const VAULT_SEEDS: SeedSchema<4> = SeedSchema::new([
+    SeedPart::Const(b"vault"),
+    SeedPart::Pubkey,
+    SeedPart::U64,
+    SeedPart::Bytes(4),
+]);
+
+#[test]
+fn derives_same_address_as_manual_seeds() {
+    let program_id = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let values = [
+        SeedValue::Pubkey(&owner),
+        SeedValue::U64(42),
+        SeedValue::Bytes(b"abcd"),
+    ];
+
+    let (address, bump) = VAULT_SEEDS.find_address(&program_id, &values).unwrap();
+    let expected = Pubkey::find_program_address(
+        &[b"vault", owner.as_ref(), &42u64.to_le_bytes(), b"abcd"],
+        &program_id,
+    );
+
+    assert_eq!((address, bump), expected);
+    let signer = VAULT_SEEDS.signer(&program_id, &values).unwrap();
+    assert_eq!(*signer.address(), address);
+    assert_eq!(
+        VAULT_SEEDS
+            .signer_with_bump(&program_id, &values, bump)
+            .unwrap(),
+        signer
+    );
+}
+
+#[test]
+fn rejects_values_not_matching_schema() {
+    let program_id = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let cases: [&[SeedValue]; 4] = [
+        &[SeedValue::Pubkey(&owner), SeedValue::U64(42)],
+        &[
+            SeedValue::U64(42),
+            SeedValue::Pubkey(&owner),
+            SeedValue::Bytes(b"abcd"),
+        ],
+        &[
+            SeedValue::Pubkey(&owner),
+            SeedValue::U64(42),
+            SeedValue::Bytes(b"abc"),
+        ],
+        &[
+            SeedValue::Pubkey(&owner),
+            SeedValue::U64(42),
+            SeedValue::Bytes(b"abcd"),
+            SeedValue::U64(1),
+        ],
+    ];
+
+    for values in cases {
+        let err = VAULT_SEEDS.find_address(&program_id, values).unwrap_err();
+        assert!(matches!(err, Errors::SeedSchemaMismatch));
+    }
+}
+
+#[test]
+#[should_panic(expected = "seed is longer than 32 bytes")]
+fn rejects_seed_longer_than_limit() {
+    let _ = SeedSchema::new([SeedPart::Bytes(33)]);
+}
+
+#[test]
+#[should_panic(expected = "too many seeds")]
+fn rejects_too_many_seeds() {
+    let _ = SeedSchema::new([SeedPart::U64; 16]);
+}
+
+#[test]
+fn hashed_seed_is_sha256_of_input() {
+    let url = b"https://example.com/a/very/long/identifier/that/does/not/fit/in/a/seed";
+    let digest = solana_program::hash::hash(url).to_bytes();
+
+    assert_eq!(HashedSeed::hash(url), digest);
+
+    const NAMED: SeedSchema<2> = SeedSchema::new([SeedPart::Const(b"name"), SeedPart::Hashed]);
+    let program_id = Pubkey::new_unique();
+    let (address, _) = NAMED
+        .find_address(&program_id, &[SeedValue::Hashed(url)])
+        .unwrap();
+    assert_eq!(
+        address,
+        Pubkey::find_program_address(&[b"name", &digest], &program_id).0
+    );
+}
+
+#[test]
+fn hashed_parts_are_length_prefixed() {
+    let expected =
+        solana_program::hash::hashv(&[&2u32.to_le_bytes(), b"ab", &1u32.to_le_bytes(), b"c"]);
+
+    assert_eq!(HashedSeed::hash_parts(&[b"ab", b"c"]), expected.to_bytes());
+    assert_ne!(
+        HashedSeed::hash_parts(&[b"ab", b"c"]),
+        HashedSeed::hash_parts(&[b"a", b"bc"])
+    );
+}
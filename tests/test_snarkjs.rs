@@ -0,0 +1,137 @@
+#![cfg(feature = "client")]
+
+use num_bigint::BigUint;
+use solana_bn254::prelude::alt_bn128_multiplication;
+use solana_helpers::errors::Errors;
+use solana_helpers::zk::groth16::{Groth16, Proof};
+use solana_helpers::zk::snarkjs::Snarkjs;
+
+/// The G2 generator as snarkjs writes it: `[[x_c0, x_c1], [y_c0, y_c1], ["1", "0"]]`.
+const G2: &str = r#"[
+    ["10857046999023057135944570762232829481370756359578518086990519993285655852781",
+     "11559732032986387107991004021392285783925812861821192530917403151452391805634"],
+    ["8495653923123431417604973247489272438418190587263600148770280649306958101930",
+     "4082367875863433681332203403145435568316851327593401208105741076214120093531"],
+    ["1", "0"]
+]"#;
+
+/// `k` times the G1 generator, as a snarkjs projective point.
+fn g1(k: u64) -> String {
+    let mut input = [0u8; 96];
+    input[31] = 1;
+    input[63] = 2;
+    input[88..].copy_from_slice(&k.to_be_bytes());
+    let point = alt_bn128_multiplication(&input).unwrap();
+    let x = BigUint::from_bytes_be(&point[..32]);
+    let y = BigUint::from_bytes_be(&point[32..]);
+    format!(r#"["{x}", "{y}", "1"]"#)
+}
+
+/// Artifacts of a proof built like the `test_groth16` fixture: every G2 point is the
+/// generator, so `A = alpha + vk_x + C` in G1.
+fn verification_key() -> String {
+    format!(
+        r#"{{
+            "protocol": "groth16",
+            "curve": "bn128",
+            "nPublic": 2,
+            "vk_alpha_1": {alpha},
+            "vk_beta_2": {G2},
+            "vk_gamma_2": {G2},
+            "vk_delta_2": {G2},
+            "IC": [{ic0}, {ic1}, {ic2}]
+        }}"#,
+        alpha = g1(3),
+        ic0 = g1(5),
+        ic1 = g1(7),
+        ic2 = g1(11),
+    )
+}
+
+fn proof_json() -> String {
+    format!(
+        r#"{{"pi_a": {a}, "pi_b": {G2}, "pi_c": {c}, "protocol": "groth16", "curve": "bn128"}}"#,
+        a = g1(134),
+        c = g1(13),
+    )
+}
+
+const PUBLIC: &str = r#"["2", "9"]"#;
+
+#[test]
+fn converted_artifacts_verify() {
+    let vk = Snarkjs::verifying_key(&verification_key()).unwrap();
+    let inputs = Snarkjs::public_inputs(PUBLIC).unwrap();
+    assert_eq!(inputs[1][31], 9);
+
+    let proof = Snarkjs::proof(&proof_json()).unwrap();
+    Groth16::verify(&vk.as_verifying_key(), &proof, &inputs).unwrap();
+    let negated = Snarkjs::negated_proof(&proof_json()).unwrap();
+    assert_eq!(negated.a, Groth16::negate_g1(&proof.a).unwrap());
+    Groth16::verify_negated(&vk.as_verifying_key(), &negated, &inputs).unwrap();
+}
+
+/// Reads an artifact of the circom `Multiplier2` circuit proving `3 * 11 = 33`, see
+/// `tests/fixtures/multiplier2/README.md`.
+fn multiplier2(name: &str) -> String {
+    let path = format!(
+        "{}/tests/fixtures/multiplier2/{name}",
+        env!("CARGO_MANIFEST_DIR")
+    );
+    std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("{path}: {err}"))
+}
+
+#[test]
+#[ignore = "needs the snarkjs artifacts of tests/fixtures/multiplier2, see its README"]
+fn circuit_fixture_verifies() {
+    let proof_json = multiplier2("proof.json");
+    let vk = Snarkjs::verifying_key(&multiplier2("verification_key.json")).unwrap();
+    let proof = Snarkjs::proof(&proof_json).unwrap();
+    let inputs = Snarkjs::public_inputs(&multiplier2("public.json")).unwrap();
+    assert_eq!(inputs.len(), 1);
+    assert_eq!(inputs[0][31], 33);
+
+    Groth16::verify(&vk.as_verifying_key(), &proof, &inputs).unwrap();
+    let negated = Snarkjs::negated_proof(&proof_json).unwrap();
+    Groth16::verify_negated(&vk.as_verifying_key(), &negated, &inputs).unwrap();
+
+    let wrong = Snarkjs::public_inputs(r#"["34"]"#).unwrap();
+    assert!(Groth16::verify(&vk.as_verifying_key(), &proof, &wrong).is_err());
+    let swapped = Proof {
+        a: proof.c,
+        c: proof.a,
+        ..proof
+    };
+    assert!(Groth16::verify(&vk.as_verifying_key(), &swapped, &inputs).is_err());
+}
+
+#[test]
+fn emits_verifying_key_constants() {
+    let vk = Snarkjs::verifying_key(&verification_key()).unwrap();
+    let source = vk.to_rust("VERIFYING_KEY");
+
+    assert!(source.starts_with(
+        "pub const VERIFYING_KEY: solana_helpers::zk::groth16::VerifyingKey<'static> ="
+    ));
+    // The G2 generator starts with `x_c1`.
+    assert!(source.contains("beta_g2: [\n            0x19, 0x8e, 0x93, 0x93,"));
+    assert_eq!(source.matches("0x").count(), 64 + 3 * 128 + 3 * 64);
+    assert!(source.ends_with("        ],\n    };\n"));
+}
+
+#[test]
+fn rejects_malformed_artifacts() {
+    let wrong_curve = proof_json().replace("bn128", "bls12381");
+    let wrong_count = verification_key().replace(r#""nPublic": 2"#, r#""nPublic": 3"#);
+    let too_large = format!(r#"["{}"]"#, BigUint::from(1u8) << 256);
+
+    for result in [
+        Snarkjs::proof(&wrong_curve).map(|_| ()),
+        Snarkjs::proof(r#"{"pi_a": ["1", "2", "3"]}"#).map(|_| ()),
+        Snarkjs::verifying_key(&wrong_count).map(|_| ()),
+        Snarkjs::public_inputs(&too_large).map(|_| ()),
+        Snarkjs::public_inputs(r#"[2]"#).map(|_| ()),
+    ] {
+        assert!(matches!(result, Err(Errors::InvalidSnarkjsArtifact)));
+    }
+}
@@ -0,0 +1,114 @@
+mod stubs;
+
+use borsh::BorshSerialize;
+use solana_helpers::helpers::fixed::{BoundedVec, FixedString};
+use solana_helpers::helpers::generic::Generic;
+use solana_helpers::helpers::space::AccountSpace;
+use solana_program::pubkey::Pubkey;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+#[derive(AccountSpace, BorshSerialize)]
+#[account_space(header = [u8; 8])]
+struct Profile {
+    owner: Pubkey,
+    bump: u8,
+    score: Option<u64>,
+    name: FixedString<8>,
+    tags: BoundedVec<u16, 3>,
+    history: [u32; 2],
+}
+
+#[derive(AccountSpace, BorshSerialize)]
+struct Pair<T>(T, u8);
+
+#[derive(AccountSpace, BorshSerialize)]
+struct Marker;
+
+#[derive(AccountSpace, BorshSerialize)]
+enum Slot<T> {
+    Empty,
+    Single(T),
+    Range {
+        start: T,
+        end: T,
+        tags: BoundedVec<u8, 2>,
+    },
+}
+
+fn profile(score: Option<u64>, tags: Vec<u16>) -> Profile {
+    Profile {
+        owner: Pubkey::new_unique(),
+        bump: 255,
+        score,
+        name: "alice".parse().unwrap(),
+        tags: tags.try_into().unwrap(),
+        history: [1, 2],
+    }
+}
+
+#[test]
+fn max_space_covers_largest_value() {
+    assert_eq!(Profile::MAX_SPACE, 32 + 1 + 9 + 12 + (4 + 6) + 8);
+    assert_eq!(Profile::max_space(), 8 + Profile::MAX_SPACE);
+
+    let largest = profile(Some(1), vec![1, 2, 3]);
+    assert_eq!(largest.space(), Profile::MAX_SPACE);
+    assert_eq!(borsh::to_vec(&largest).unwrap().len(), Profile::MAX_SPACE);
+}
+
+#[test]
+fn derives_for_tuple_unit_generic_and_enum_types() {
+    assert_eq!(Pair::<u64>::MAX_SPACE, 9);
+    assert_eq!(Pair::<u64>::max_space(), 9);
+    assert_eq!(Marker::MAX_SPACE, 0);
+    assert_eq!(Slot::<u32>::MAX_SPACE, 1 + 4 + 4 + (4 + 2));
+
+    let values = [
+        Slot::Empty,
+        Slot::Single(7),
+        Slot::Range {
+            start: 1,
+            end: 2,
+            tags: vec![3].try_into().unwrap(),
+        },
+    ];
+    for value in &values {
+        assert_eq!(value.space(), borsh::to_vec(value).unwrap().len());
+    }
+    let pair = Pair(5u16, 1);
+    assert_eq!(pair.space(), borsh::to_vec(&pair).unwrap().len());
+    assert_eq!(Marker.space(), borsh::to_vec(&Marker).unwrap().len());
+}
+
+#[test]
+fn space_matches_serialized_value() {
+    let value = profile(None, vec![7]);
+
+    assert_eq!(value.space(), borsh::to_vec(&value).unwrap().len());
+    assert_eq!(value.account_space(), 8 + value.space());
+    assert!(value.space() < Profile::MAX_SPACE);
+}
+
+#[test]
+fn create_pda_account_for_allocates_max_space() {
+    install();
+    let seeds: &[&[u8]] = &[b"profile"];
+    let (pda, _) = Pubkey::find_program_address(seeds, &TEST_PROGRAM_ID);
+    let mut target = MockAccount::wallet(pda, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+
+    Generic::create_pda_account_for::<Profile>(
+        &target.info(false, true),
+        &system.info(false, false),
+        &payer.info(true, true),
+        &TEST_PROGRAM_ID,
+        seeds,
+    )
+    .unwrap();
+
+    assert_eq!(target.owner(), TEST_PROGRAM_ID);
+    assert_eq!(target.data().len(), Profile::max_space());
+}
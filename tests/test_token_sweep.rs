@@ -0,0 +1,177 @@
+mod stubs;
+
+use solana_helpers::helpers::closers::Closers;
+use solana_helpers::helpers::pda_signer::PdaSigner;
+use solana_program::program_option::COption;
+use solana_program::program_pack::Pack;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use spl_token::state::{Account, AccountState};
+use spl_token_2022::extension::transfer_fee::{TransferFeeAmount, TransferFeeConfig};
+use spl_token_2022::extension::{
+    BaseStateWithExtensions, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensions,
+    StateWithExtensionsMut,
+};
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+fn authority() -> PdaSigner {
+    PdaSigner::derive(&TEST_PROGRAM_ID, &[b"authority"])
+}
+
+#[test]
+fn sweeps_remaining_tokens_before_closing() {
+    install();
+    let signer = authority();
+    let owner = *signer.address();
+    let mint_key = Pubkey::new_unique();
+    let mut account = MockAccount::token_account(Pubkey::new_unique(), mint_key, owner, 30);
+    let mut sweep = MockAccount::token_account(Pubkey::new_unique(), mint_key, owner, 0);
+    let mut mint = MockAccount::mint(mint_key, 30, 6);
+    let mut destination = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut authority = MockAccount::wallet(owner, 0);
+    let mut token_program = MockAccount::program(spl_token::ID);
+    let refund = account.lamports();
+
+    Closers::close_token_account_with_sweep(
+        account.info(false, true),
+        sweep.info(false, true),
+        mint.info(false, false),
+        destination.info(false, true),
+        authority.info(false, false),
+        token_program.info(false, false),
+        &[],
+        &[&signer],
+    )
+    .unwrap();
+
+    assert_eq!(Account::unpack(sweep.data()).unwrap().amount, 30);
+    assert_eq!(account.lamports(), 0);
+    assert_eq!(destination.lamports(), refund);
+}
+
+#[test]
+fn unwraps_native_balance() {
+    install();
+    let signer = authority();
+    let owner = *signer.address();
+    let rent = Rent::default().minimum_balance(Account::LEN);
+    let state = Account {
+        mint: spl_token::native_mint::ID,
+        owner,
+        amount: 1_000_000,
+        state: AccountState::Initialized,
+        is_native: COption::Some(rent),
+        ..Account::default()
+    };
+    let mut account = MockAccount::packed(Pubkey::new_unique(), spl_token::ID, state);
+    **account.info(false, true).try_borrow_mut_lamports().unwrap() = rent + 1_000_000;
+    let mut sweep = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut mint = MockAccount::mint(spl_token::native_mint::ID, 0, 9);
+    let mut destination = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut authority = MockAccount::wallet(owner, 0);
+    let mut token_program = MockAccount::program(spl_token::ID);
+
+    Closers::close_token_account_with_sweep(
+        account.info(false, true),
+        sweep.info(false, true),
+        mint.info(false, false),
+        destination.info(false, true),
+        authority.info(false, false),
+        token_program.info(false, false),
+        &[],
+        &[&signer],
+    )
+    .unwrap();
+
+    assert_eq!(account.lamports(), 0);
+    assert_eq!(destination.lamports(), rent + 1_000_000);
+}
+
+#[test]
+fn harvests_withheld_fees_before_closing() {
+    install();
+    let signer = authority();
+    let owner = *signer.address();
+    let mint_key = Pubkey::new_unique();
+
+    let mint_len = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+        ExtensionType::TransferFeeConfig,
+    ])
+    .unwrap();
+    let mut mint_data = vec![0u8; mint_len];
+    let mut mint_state =
+        StateWithExtensionsMut::<spl_token_2022::state::Mint>::unpack_uninitialized(&mut mint_data)
+            .unwrap();
+    mint_state
+        .init_extension::<TransferFeeConfig>(true)
+        .unwrap();
+    mint_state.base = spl_token_2022::state::Mint {
+        decimals: 6,
+        is_initialized: true,
+        ..spl_token_2022::state::Mint::default()
+    };
+    mint_state.pack_base();
+    mint_state.init_account_type().unwrap();
+
+    let account_len =
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&[
+            ExtensionType::TransferFeeAmount,
+        ])
+        .unwrap();
+    let mut account_data = vec![0u8; account_len];
+    let mut account_state =
+        StateWithExtensionsMut::<spl_token_2022::state::Account>::unpack_uninitialized(
+            &mut account_data,
+        )
+        .unwrap();
+    account_state
+        .init_extension::<TransferFeeAmount>(true)
+        .unwrap()
+        .withheld_amount = 5.into();
+    account_state.base = spl_token_2022::state::Account {
+        mint: mint_key,
+        owner,
+        state: spl_token_2022::state::AccountState::Initialized,
+        ..spl_token_2022::state::Account::default()
+    };
+    account_state.pack_base();
+    account_state.init_account_type().unwrap();
+
+    let rent = Rent::default();
+    let mut account = MockAccount::new(
+        Pubkey::new_unique(),
+        spl_token_2022::ID,
+        rent.minimum_balance(account_len),
+        &account_data,
+    );
+    let mut mint = MockAccount::new(
+        mint_key,
+        spl_token_2022::ID,
+        rent.minimum_balance(mint_len),
+        &mint_data,
+    );
+    let mut sweep = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut destination = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut authority = MockAccount::wallet(owner, 0);
+    let mut token_program = MockAccount::program(spl_token_2022::ID);
+
+    Closers::close_token_account_with_sweep(
+        account.info(false, true),
+        sweep.info(false, true),
+        mint.info(false, true),
+        destination.info(false, true),
+        authority.info(false, false),
+        token_program.info(false, false),
+        &[],
+        &[&signer],
+    )
+    .unwrap();
+
+    let mint_state =
+        StateWithExtensions::<spl_token_2022::state::Mint>::unpack(mint.data()).unwrap();
+    let config = mint_state.get_extension::<TransferFeeConfig>().unwrap();
+    assert_eq!(u64::from(config.withheld_amount), 5);
+    assert_eq!(account.lamports(), 0);
+    assert_eq!(destination.lamports(), rent.minimum_balance(account_len));
+}
@@ -0,0 +1,102 @@
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::conversions::Conversions;
+use solana_helpers::helpers::u256::U256;
+use solana_helpers::zk::groth16::SCALAR_FIELD_MODULUS;
+
+#[test]
+fn round_trips_bytes() {
+    let mut big_endian = [0u8; 32];
+    for (i, byte) in big_endian.iter_mut().enumerate() {
+        *byte = u8::try_from(i).unwrap();
+    }
+    let value = U256::from_be_bytes(&big_endian);
+    let mut little_endian = big_endian;
+    little_endian.reverse();
+
+    assert_eq!(value.to_be_bytes(), big_endian);
+    assert_eq!(value.to_le_bytes(), little_endian);
+    assert_eq!(U256::from_le_bytes(&little_endian), value);
+    assert_eq!(
+        Conversions::number_array_to_u256(&[0x01, 0x02], false).unwrap(),
+        U256::from(0x0102u64)
+    );
+    assert_eq!(
+        Conversions::number_array_to_u256(&[0x01, 0x02], true).unwrap(),
+        U256::from(0x0201u64)
+    );
+    assert!(matches!(
+        Conversions::number_array_to_u256(&[0; 33], false),
+        Err(Errors::NumericalOverflow)
+    ));
+    assert_eq!(
+        U256::BN254_BASE_MODULUS.to_string(),
+        "21888242871839275222246405745257275088696311157297823662689037894645226208583"
+    );
+    assert_eq!(
+        U256::BN254_SCALAR_MODULUS.to_string(),
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617"
+    );
+    assert_eq!(
+        U256::BN254_SCALAR_MODULUS.to_be_bytes(),
+        SCALAR_FIELD_MODULUS
+    );
+    assert_eq!(U256::from(u128::MAX).to_string(), u128::MAX.to_string());
+    assert_eq!(U256::ZERO.to_string(), "0");
+}
+
+#[test]
+fn checked_arithmetic() {
+    let big = U256::from(u128::MAX);
+    let carried = big.checked_add(&U256::ONE).unwrap();
+
+    assert_eq!(carried.checked_sub(&U256::ONE), Some(big));
+    assert_eq!(big.checked_mul(&big).unwrap().checked_div(&big), Some(big));
+    assert_eq!(
+        U256::from(1_000_003u64).checked_rem(&U256::from(1_000u64)),
+        Some(U256::from(3u64))
+    );
+    assert_eq!(U256::MAX.checked_add(&U256::ONE), None);
+    assert_eq!(U256::ZERO.checked_sub(&U256::ONE), None);
+    assert_eq!(U256::MAX.checked_mul(&U256::from(2u64)), None);
+    assert_eq!(carried.checked_mul(&carried), None);
+    assert_eq!(U256::ONE.checked_div(&U256::ZERO), None);
+    assert_eq!(U256::MAX.to_u64(), None);
+    assert_eq!(U256::from(7u64).to_u64(), Some(7));
+}
+
+#[test]
+fn compares_against_field_moduli() {
+    let modulus = U256::BN254_SCALAR_MODULUS;
+    let below = modulus.checked_sub(&U256::ONE).unwrap();
+
+    assert!(below < modulus);
+    assert!(U256::from(u128::MAX) < U256::from_be_bytes(&[1; 32]));
+    assert!(U256::BN254_BASE_MODULUS > modulus);
+    assert_eq!(below.ensure_below(&modulus).unwrap(), below);
+    assert!(matches!(
+        modulus.ensure_below(&modulus),
+        Err(Errors::FieldElementOutOfRange)
+    ));
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn converts_to_and_from_bigint() {
+    use num_bigint::BigInt;
+
+    let value = U256::BN254_SCALAR_MODULUS;
+    let bigint = BigInt::from(value);
+
+    assert_eq!(
+        bigint,
+        Conversions::number_array_to_bigint(&SCALAR_FIELD_MODULUS, false)
+    );
+    assert_eq!(bigint.to_string(), value.to_string());
+    assert_eq!(U256::try_from(&bigint).unwrap(), value);
+    for out_of_range in [BigInt::from(-1), BigInt::from(1) << 256] {
+        assert!(matches!(
+            U256::try_from(&out_of_range),
+            Err(Errors::NumericalOverflow)
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::vaults::SolVault;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+const SEEDS: &[&[u8]] = &[b"vault"];
+
+#[test]
+fn fund_withdraw_and_close() {
+    install();
+    let (vault_key, _) = SolVault::derive(&TEST_PROGRAM_ID, SEEDS);
+    let mut vault = MockAccount::wallet(vault_key, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let (vault, payer, system) = (
+        vault.info(false, true),
+        payer.info(true, true),
+        system.info(false, false),
+    );
+
+    SolVault::fund(
+        payer.clone(),
+        vault.clone(),
+        system.clone(),
+        5_000_000,
+        &TEST_PROGRAM_ID,
+        SEEDS,
+    )
+    .unwrap();
+    assert_eq!(vault.lamports(), 5_000_000);
+
+    SolVault::withdraw(
+        vault.clone(),
+        payer.clone(),
+        system.clone(),
+        1_000_000,
+        &TEST_PROGRAM_ID,
+        SEEDS,
+    )
+    .unwrap();
+    assert_eq!(vault.lamports(), 4_000_000);
+
+    SolVault::close(
+        vault.clone(),
+        payer.clone(),
+        system,
+        &TEST_PROGRAM_ID,
+        SEEDS,
+    )
+    .unwrap();
+    assert_eq!(vault.lamports(), 0);
+    assert_eq!(payer.lamports(), 10_000_000_000);
+}
+
+#[test]
+fn vault_stays_rent_exempt() {
+    install();
+    let minimum = Rent::default().minimum_balance(0);
+    let (vault_key, _) = SolVault::derive(&TEST_PROGRAM_ID, SEEDS);
+    let mut vault = MockAccount::wallet(vault_key, 0);
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let (vault, payer, system) = (
+        vault.info(false, true),
+        payer.info(true, true),
+        system.info(false, false),
+    );
+
+    let err = SolVault::fund(
+        payer.clone(),
+        vault.clone(),
+        system.clone(),
+        minimum - 1,
+        &TEST_PROGRAM_ID,
+        SEEDS,
+    );
+    assert!(matches!(err, Err(Errors::InsufficientFundsForRent)));
+
+    SolVault::fund(
+        payer.clone(),
+        vault.clone(),
+        system.clone(),
+        minimum,
+        &TEST_PROGRAM_ID,
+        SEEDS,
+    )
+    .unwrap();
+    let err = SolVault::withdraw(vault, payer, system, 1, &TEST_PROGRAM_ID, SEEDS);
+    assert!(matches!(err, Err(Errors::InsufficientFundsForRent)));
+}
+
+#[test]
+fn rejects_wrong_vault() {
+    install();
+    let mut vault = MockAccount::wallet(Pubkey::new_unique(), 5_000_000);
+    let mut other = MockAccount::wallet(Pubkey::new_unique(), 10_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let (vault, other, system) = (
+        vault.info(false, true),
+        other.info(true, true),
+        system.info(false, false),
+    );
+
+    let err = SolVault::fund(
+        other.clone(),
+        vault.clone(),
+        system.clone(),
+        1_000_000,
+        &TEST_PROGRAM_ID,
+        SEEDS,
+    );
+    assert!(matches!(err, Err(Errors::WrongPdaAddress)));
+
+    let err = SolVault::close(vault.clone(), other, system, &TEST_PROGRAM_ID, SEEDS);
+    assert!(matches!(err, Err(Errors::WrongPdaAddress)));
+    assert_eq!(vault.lamports(), 5_000_000);
+}
@@ -0,0 +1,194 @@
+mod stubs;
+
+use solana_helpers::errors::Errors;
+use solana_helpers::helpers::generic::Generic;
+use solana_helpers::helpers::versioned::{AccountHeader, AccountSchema};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_system_interface::program as system_program;
+use stubs::mock_account::MockAccount;
+use stubs::syscall_stubs::{install, TEST_PROGRAM_ID};
+
+fn add_counter(body: &[u8]) -> Result<Vec<u8>, Errors> {
+    Ok([body, &7u64.to_le_bytes()].concat())
+}
+
+fn drop_flags(body: &[u8]) -> Result<Vec<u8>, Errors> {
+    Ok(body[4..].to_vec())
+}
+
+const CONFIG: AccountSchema = AccountSchema::new("Config", &[add_counter, drop_flags]);
+
+fn versioned_data(name: &str, version: u32, body: &[u8]) -> Vec<u8> {
+    versioned_data_paid_by(name, version, body, None)
+}
+
+fn versioned_data_paid_by(
+    name: &str,
+    version: u32,
+    body: &[u8],
+    rent_payer: Option<Pubkey>,
+) -> Vec<u8> {
+    let mut data = vec![0u8; AccountHeader::LEN + body.len()];
+    AccountHeader {
+        discriminator: Generic::derive_discriminator(name),
+        version,
+        rent_payer,
+    }
+    .write(&mut data)
+    .unwrap();
+    data[AccountHeader::LEN..].copy_from_slice(body);
+    data
+}
+
+#[test]
+fn migrates_old_layout_and_adjusts_rent() {
+    install();
+    let data = versioned_data("Config", 0, &[1, 2, 3, 4]);
+    let mut account = MockAccount::new(
+        Pubkey::new_unique(),
+        TEST_PROGRAM_ID,
+        Rent::default().minimum_balance(data.len()),
+        &data,
+    );
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 1_000_000_000);
+    let mut system = MockAccount::program(system_program::ID);
+    let (account_info, payer_info, system_info) = (
+        account.info(false, true),
+        payer.info(true, true),
+        system.info(false, false),
+    );
+
+    assert!(matches!(
+        CONFIG.body(&account_info.data.borrow()),
+        Err(Errors::UnsupportedAccountVersion)
+    ));
+    assert!(CONFIG
+        .migrate(&account_info, &TEST_PROGRAM_ID, &payer_info, &system_info)
+        .unwrap());
+    assert!(!CONFIG
+        .migrate(&account_info, &TEST_PROGRAM_ID, &payer_info, &system_info)
+        .unwrap());
+    drop((account_info, payer_info, system_info));
+
+    assert_eq!(CONFIG.body(account.data()).unwrap(), &7u64.to_le_bytes());
+    assert_eq!(CONFIG.version(account.data()).unwrap(), 2);
+    assert_eq!(
+        account.lamports(),
+        Rent::default().minimum_balance(AccountHeader::LEN + 8)
+    );
+    assert_eq!(
+        payer.lamports() + account.lamports(),
+        1_000_000_000 + Rent::default().minimum_balance(data.len())
+    );
+}
+
+#[test]
+fn shrinking_refunds_freed_rent_only() {
+    install();
+    let data = versioned_data("Config", 1, &[0; 12]);
+    let extra = 5_000;
+    let mut account = MockAccount::new(
+        Pubkey::new_unique(),
+        TEST_PROGRAM_ID,
+        Rent::default().minimum_balance(data.len()) + extra,
+        &data,
+    );
+    let mut payer = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut system = MockAccount::program(system_program::ID);
+
+    CONFIG
+        .migrate(
+            &account.info(false, true),
+            &TEST_PROGRAM_ID,
+            &payer.info(true, true),
+            &system.info(false, false),
+        )
+        .unwrap();
+
+    assert_eq!(account.data().len(), AccountHeader::LEN + 8);
+    assert_eq!(
+        account.lamports(),
+        Rent::default().minimum_balance(AccountHeader::LEN + 8) + extra
+    );
+    assert_eq!(
+        payer.lamports(),
+        Rent::default().minimum_balance(data.len())
+            - Rent::default().minimum_balance(AccountHeader::LEN + 8)
+    );
+}
+
+#[test]
+fn freed_rent_goes_to_the_rent_payer_only() {
+    install();
+    let rent_payer = Pubkey::new_unique();
+    let recorded = versioned_data_paid_by("Config", 1, &[0; 12], Some(rent_payer));
+    let unrecorded = versioned_data("Config", 1, &[0; 12]);
+    let lamports = Rent::default().minimum_balance(recorded.len());
+    let mut paid = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, lamports, &recorded);
+    let mut unpaid = MockAccount::new(Pubkey::new_unique(), TEST_PROGRAM_ID, lamports, &unrecorded);
+    let mut foreign = MockAccount::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        lamports,
+        &unrecorded,
+    );
+    let mut stranger = MockAccount::wallet(Pubkey::new_unique(), 0);
+    let mut payer = MockAccount::wallet(rent_payer, 0);
+    let mut system = MockAccount::program(system_program::ID);
+    let (paid, unpaid, foreign, signed_stranger, payer_info, system) = (
+        paid.info(false, true),
+        unpaid.info(false, true),
+        foreign.info(false, true),
+        stranger.info(true, true),
+        payer.info(false, true),
+        system.info(false, false),
+    );
+    let mut unsigned_stranger = signed_stranger.clone();
+    unsigned_stranger.is_signer = false;
+
+    let err = CONFIG
+        .migrate(&foreign, &TEST_PROGRAM_ID, &signed_stranger, &system)
+        .unwrap_err();
+    assert!(matches!(err, Errors::InvalidAccountOwner));
+    let err = CONFIG
+        .migrate(&paid, &TEST_PROGRAM_ID, &signed_stranger, &system)
+        .unwrap_err();
+    assert!(matches!(err, Errors::RentPayerMismatch));
+    let err = CONFIG
+        .migrate(&unpaid, &TEST_PROGRAM_ID, &unsigned_stranger, &system)
+        .unwrap_err();
+    assert!(matches!(err, Errors::MissingRequiredSignature));
+    assert_eq!(signed_stranger.lamports(), 0);
+
+    assert!(CONFIG
+        .migrate(&paid, &TEST_PROGRAM_ID, &payer_info, &system)
+        .unwrap());
+    assert_eq!(
+        AccountHeader::read(&paid.data.borrow()).unwrap().rent_payer,
+        Some(rent_payer)
+    );
+    assert_eq!(
+        payer_info.lamports(),
+        lamports - Rent::default().minimum_balance(AccountHeader::LEN + 8)
+    );
+}
+
+#[test]
+fn rejects_foreign_or_future_accounts() {
+    let foreign = versioned_data("Other", 0, &[]);
+    let future = versioned_data("Config", 3, &[]);
+
+    assert!(matches!(
+        CONFIG.version(&foreign),
+        Err(Errors::DiscriminatorMismatch)
+    ));
+    assert!(matches!(
+        CONFIG.version(&future),
+        Err(Errors::UnsupportedAccountVersion)
+    ));
+    assert!(matches!(
+        CONFIG.version(&[0; 4]),
+        Err(Errors::InvalidAccountData)
+    ));
+}